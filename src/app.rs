@@ -0,0 +1,30 @@
+use std::sync::Arc;
+use axum::{middleware, Extension, Router};
+use crate::auth::policy::authorize_route;
+use crate::common::email::Mailer;
+use crate::common::i18n::locale_middleware;
+use crate::database::MongoRepository;
+use crate::{auth, category, order, reservation, role, store, user};
+
+pub fn routes() -> Router {
+    Router::new()
+        .merge(user::handler::user_routes())
+        .merge(auth::handler::auth_routes())
+        .merge(store::handler::store_routes())
+        .merge(category::handler::category_routes())
+        .merge(category::product::handler::product_routes())
+        .merge(category::product::stock::handler::stock_routes())
+        .merge(category::product::restock::handler::restock_routes())
+        .merge(order::handler::order_routes())
+        .merge(reservation::handler::reservation_routes())
+        .merge(role::handler::role_routes())
+}
+
+// 라우트 정책 검사는 route_layer 로 붙여 라우팅이 끝난 요청에만 적용합니다.
+pub fn build_router(routes: Router, mongo_repo: Arc<MongoRepository>, mailer: Arc<dyn Mailer>) -> Router {
+    routes
+        .route_layer(middleware::from_fn(authorize_route))
+        .layer(Extension(mongo_repo))
+        .layer(Extension(mailer))
+        .layer(middleware::from_fn(locale_middleware))
+}
@@ -0,0 +1,245 @@
+use std::collections::BTreeSet;
+use std::convert::Infallible;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use axum::async_trait;
+use axum::extract::rejection::ExtensionRejection;
+use axum::extract::{ConnectInfo, FromRequestParts};
+use axum::http::header::{HeaderName, USER_AGENT};
+use axum::http::request::Parts;
+use crate::auth::helper::{resolve_api_key, API_KEY_HEADER};
+use crate::common::error::AppError;
+use crate::common::i18n::{set_store_locale, Message};
+use crate::common::jwt::Claims;
+use crate::config::CONFIG;
+use crate::database::MongoRepository;
+use crate::role::helper::effective_permissions;
+use crate::role::model::Permission;
+use crate::store::helper::get_store_from_headers;
+use crate::store::model::Store;
+use crate::user::helper::{authenticate, bearer_claims};
+use crate::user::model::User;
+
+#[derive(Clone)]
+pub struct CurrentStore(pub Store);
+
+#[derive(Clone)]
+pub struct AuthUser {
+    pub store: Store,
+    pub user: User,
+    pub claims: Claims,
+}
+
+// TRUSTED_PROXIES 에 등록된 프록시를 거친 요청이면 X-Forwarded-For 에서 실제 클라이언트 IP를 꺼냅니다.
+#[derive(Clone, Copy, Debug)]
+pub struct ClientIp(pub IpAddr);
+
+#[derive(Clone, Debug)]
+pub struct SessionClient {
+    pub user_agent: Option<String>,
+    pub ip: Option<IpAddr>,
+}
+
+const MAX_USER_AGENT_LENGTH: usize = 256;
+const X_FORWARDED_FOR: HeaderName = HeaderName::from_static("x-forwarded-for");
+
+// 요청한 주체와 그 주체가 가진 권한 전체입니다. API 키 요청이면 키 범위 안의 권한만 담깁니다.
+#[derive(Clone)]
+pub struct Caller {
+    pub store: Store,
+    pub user: User,
+    pub permissions: BTreeSet<Permission>,
+    pub via_api_key: bool,
+}
+
+#[derive(Clone)]
+pub struct Authorized {
+    pub store: Store,
+    pub user: User,
+    pub permissions: BTreeSet<Permission>,
+}
+
+fn mongo_repo(parts: &Parts) -> Result<Arc<MongoRepository>, AppError> {
+    parts
+        .extensions
+        .get::<Arc<MongoRepository>>()
+        .cloned()
+        .ok_or_else(|| AppError::Internal(Message::new("DATABASE_NOT_INITIALIZED")))
+}
+
+// 한 요청 안에서 여러 추출기가 쓰여도 스토어 조회와 토큰 검증은 한 번만 하도록 결과를 요청 확장에 보관합니다.
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for CurrentStore {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        if let Some(current) = parts.extensions.get::<CurrentStore>() {
+            return Ok(current.clone());
+        }
+
+        let mongo_repo = mongo_repo(parts)?;
+        let store = get_store_from_headers(&parts.headers, &mongo_repo).await?;
+        set_store_locale(store.default_locale);
+
+        let current = CurrentStore(store);
+        parts.extensions.insert(current.clone());
+        Ok(current)
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for AuthUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        if let Some(auth_user) = parts.extensions.get::<AuthUser>() {
+            return Ok(auth_user.clone());
+        }
+
+        let claims = bearer_claims(&parts.headers)?;
+        let CurrentStore(store) = CurrentStore::from_request_parts(parts, state).await?;
+        let mongo_repo = mongo_repo(parts)?;
+        let (user, claims) = authenticate(claims, &store, &mongo_repo).await?;
+
+        let auth_user = AuthUser { store, user, claims };
+        parts.extensions.insert(auth_user.clone());
+        Ok(auth_user)
+    }
+}
+
+// X-Api-Key 가 있으면 API 키로, 없으면 로그인 토큰으로 요청자를 확인합니다.
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for Caller {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        if let Some(caller) = parts.extensions.get::<Caller>() {
+            return Ok(caller.clone());
+        }
+
+        let mongo_repo = mongo_repo(parts)?;
+        let caller = if parts.headers.contains_key(API_KEY_HEADER) {
+            let CurrentStore(store) = CurrentStore::from_request_parts(parts, state).await?;
+            resolve_api_key(&mongo_repo, &parts.headers, store).await?
+        } else {
+            let AuthUser { store, user, .. } = AuthUser::from_request_parts(parts, state).await?;
+            let permissions = effective_permissions(&mongo_repo, &store.object_id.unwrap(), &user).await?;
+            Caller { store, user, permissions, via_api_key: false }
+        };
+
+        parts.extensions.insert(caller.clone());
+        Ok(caller)
+    }
+}
+
+// 권한 검사는 라우트 정책 미들웨어가 맡으므로 여기서는 그 결과만 꺼냅니다.
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for Authorized {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<Authorized>()
+            .cloned()
+            .ok_or_else(|| AppError::Internal(Message::new("ROUTE_POLICY_MISSING")))
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for SessionClient {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let user_agent = parts
+            .headers
+            .get(USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.chars().take(MAX_USER_AGENT_LENGTH).collect());
+        let ip = client_ip(parts);
+
+        Ok(SessionClient { user_agent, ip })
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for ClientIp {
+    type Rejection = ExtensionRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        match client_ip(parts) {
+            Some(ip) => Ok(ClientIp(ip)),
+            None => ConnectInfo::<SocketAddr>::from_request_parts(parts, state)
+                .await
+                .map(|ConnectInfo(address)| ClientIp(address.ip())),
+        }
+    }
+}
+
+fn client_ip(parts: &Parts) -> Option<IpAddr> {
+    let peer = parts
+        .extensions
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(address)| address.ip())?;
+    let forwarded_for: Vec<&str> = parts
+        .headers
+        .get_all(X_FORWARDED_FOR)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .collect();
+
+    Some(resolve_client_ip(peer, &forwarded_for.join(","), &CONFIG.trusted_proxies))
+}
+
+// 오른쪽(가장 가까운 홉)부터 신뢰하는 프록시를 건너뛰며, 처음 만나는 신뢰하지 않는 주소를 클라이언트로 봅니다.
+fn resolve_client_ip(peer: IpAddr, forwarded_for: &str, trusted_proxies: &[IpAddr]) -> IpAddr {
+    let mut client = peer;
+    if !trusted_proxies.contains(&client) {
+        return client;
+    }
+
+    for hop in forwarded_for.rsplit(',').map(str::trim).filter(|hop| !hop.is_empty()) {
+        match hop.parse::<IpAddr>() {
+            Ok(ip) => client = ip,
+            Err(_) => break,
+        }
+        if !trusted_proxies.contains(&client) {
+            break;
+        }
+    }
+    client
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(value: &str) -> IpAddr {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn untrusted_peer_ignores_forwarded_for() {
+        let client = resolve_client_ip(ip("203.0.113.7"), "198.51.100.1", &[ip("10.0.0.1")]);
+        assert_eq!(client, ip("203.0.113.7"));
+    }
+
+    #[test]
+    fn trusted_proxy_uses_nearest_untrusted_hop() {
+        let trusted = [ip("10.0.0.1"), ip("10.0.0.2")];
+        let client = resolve_client_ip(ip("10.0.0.1"), "1.2.3.4, 198.51.100.1, 10.0.0.2", &trusted);
+        assert_eq!(client, ip("198.51.100.1"));
+    }
+
+    #[test]
+    fn trusted_proxy_without_header_keeps_peer() {
+        let client = resolve_client_ip(ip("10.0.0.1"), "", &[ip("10.0.0.1")]);
+        assert_eq!(client, ip("10.0.0.1"));
+    }
+
+    #[test]
+    fn malformed_hop_stops_the_walk() {
+        let client = resolve_client_ip(ip("10.0.0.1"), "198.51.100.1, unknown", &[ip("10.0.0.1")]);
+        assert_eq!(client, ip("10.0.0.1"));
+    }
+}
@@ -0,0 +1,393 @@
+use std::str::FromStr;
+use std::sync::Arc;
+use axum::extract::Path;
+use axum::http::{Method, StatusCode};
+use axum::{Extension, Json, Router};
+use axum::response::IntoResponse;
+use crate::auth::policy::PolicyRouter;
+use bson::oid::ObjectId;
+use bcrypt::{hash, DEFAULT_COST};
+use chrono::{DateTime, Utc};
+use crate::auth::extractor::{AuthUser, Authorized, ClientIp, CurrentStore, SessionClient};
+use crate::auth::helper::{
+    ensure_login_allowed, generate_api_key, generate_recovery_codes, hash_recovery_code, hash_token,
+    issue_token_response, login_attempt_keys, revoke_all_sessions, spawn_password_reset_email,
+    start_session, verify_second_factor,
+};
+use crate::auth::model::{
+    ApiKey, ApiKeyCreatedResponse, ApiKeyListResponse, CreateApiKeyRequest, ForgotPasswordRequest, LoginAttempt,
+    RecoveryCodesResponse, RefreshRequest, ResetPasswordRequest, RevokedToken, TwoFactorCodeRequest,
+    TwoFactorLoginRequest, TwoFactorSetupResponse,
+};
+use crate::common::crypto::STOCK_CIPHER;
+use crate::common::email::Mailer;
+use crate::common::error::AppError;
+use crate::common::id::parse_object_id;
+use crate::common::i18n::Message;
+use crate::common::jwt::validate_challenge_jwt;
+use crate::common::totp::{generate_secret, otpauth_uri, verify_code};
+use crate::common::response::ErrorResponse;
+use crate::common::types::Status;
+use crate::database::MongoRepository;
+use crate::role::helper::{effective_permissions, ensure_can_grant};
+use crate::role::model::Permission;
+use crate::user::helper::{ensure_not_suspended, validate_new_password};
+
+const MAX_API_KEY_NAME_LENGTH: usize = 64;
+
+pub fn auth_routes() -> Router {
+    Router::new()
+        .policy_route(Method::POST, "/api/auth/refresh", refresh_token)
+        .policy_route(Method::POST, "/api/auth/logout", logout)
+        .policy_route(Method::POST, "/api/auth/logout/all", logout_all)
+        .policy_route(Method::POST, "/api/auth/password/forgot", forgot_password)
+        .policy_route(Method::POST, "/api/auth/password/reset", reset_password)
+        .policy_route(Method::POST, "/api/auth/2fa/setup", setup_two_factor)
+        .policy_route(Method::POST, "/api/auth/2fa/confirm", confirm_two_factor)
+        .policy_route(Method::POST, "/api/auth/2fa/disable", disable_two_factor)
+        .policy_route(Method::POST, "/api/auth/2fa/login", login_two_factor)
+        .policy_route(Method::POST, "/api/admin/users/:user_id/unlock", unlock_user)
+        .policy_route(Method::GET, "/api/admin/api-keys", list_api_keys)
+        .policy_route(Method::POST, "/api/admin/api-keys", create_api_key)
+        .policy_route(Method::DELETE, "/api/admin/api-keys/:key_id", revoke_api_key)
+}
+
+pub async fn refresh_token(
+    CurrentStore(store): CurrentStore,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Json(body): Json<RefreshRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let store_id = store.object_id.unwrap();
+    let token_hash = hash_token(&body.refresh_token);
+
+    let Some(token) = mongo_repo.use_refresh_token(&store_id, &token_hash).await? else {
+        if let Some(token) = mongo_repo.find_refresh_token(&store_id, &token_hash).await? {
+            // 재사용이 감지되면 토큰 패밀리와 함께 그 세션과 세션으로 발급된 액세스 토큰도 끊습니다.
+            if token.used || token.revoked {
+                mongo_repo.revoke_refresh_token_family(&token.family_id).await?;
+                mongo_repo.delete_session(&store_id, &token.user_id, &token.family_id).await?;
+                mongo_repo
+                    .revoke_access_token(RevokedToken::session(store_id, token.user_id, token.family_id.to_hex()))
+                    .await?;
+                return Err(AppError::Unauthorized(Message::new("REFRESH_TOKEN_REUSED")));
+            }
+        }
+        return Err(AppError::Unauthorized(Message::new("INVALID_REFRESH_TOKEN")));
+    };
+
+    let Some(user) = mongo_repo.find_user_by_id(&store_id, &token.user_id).await? else {
+        mongo_repo.revoke_refresh_token_family(&token.family_id).await?;
+        return Err(AppError::Unauthorized(Message::new("USER_NOT_FOUND")));
+    };
+
+    if user.suspended {
+        mongo_repo.revoke_refresh_token_family(&token.family_id).await?;
+        return Err(AppError::Forbidden(Message::new("ACCOUNT_SUSPENDED")));
+    }
+
+    // 갱신 도중 세션이 끊겼다면 새 토큰을 내주지 않습니다.
+    if !mongo_repo.touch_session(&store_id, &token.user_id, &token.family_id).await?
+        || mongo_repo.is_refresh_token_family_revoked(&token.family_id).await?
+    {
+        mongo_repo.revoke_refresh_token_family(&token.family_id).await?;
+        return Err(AppError::Unauthorized(Message::new("INVALID_REFRESH_TOKEN")));
+    }
+
+    issue_token_response(&mongo_repo, token.user_id, store_id, Some(token.family_id)).await
+}
+
+pub async fn logout(
+    AuthUser { store, user, claims }: AuthUser,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    let store_id = store.object_id.unwrap();
+    let user_id = user.object_id.unwrap();
+    let expires_at = DateTime::from_timestamp(claims.exp as i64, 0).unwrap_or_default();
+
+    if let Ok(session_id) = ObjectId::from_str(&claims.sid) {
+        mongo_repo.revoke_refresh_token_family(&session_id).await?;
+        mongo_repo.delete_session(&store_id, &user_id, &session_id).await?;
+    }
+    mongo_repo
+        .revoke_access_token(RevokedToken::single(store_id, user_id, claims.jti.clone(), expires_at))
+        .await?;
+
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("LOGGED_OUT").localize(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn logout_all(
+    AuthUser { store, user, .. }: AuthUser,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    revoke_all_sessions(&mongo_repo, store.object_id.unwrap(), user.object_id.unwrap()).await?;
+
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("LOGGED_OUT_EVERYWHERE").localize(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn forgot_password(
+    CurrentStore(store): CurrentStore,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Extension(mailer): Extension<Arc<dyn Mailer>>,
+    Json(body): Json<ForgotPasswordRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    spawn_password_reset_email(mongo_repo.clone(), mailer.clone(), store, body.email);
+
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("PASSWORD_RESET_REQUESTED").localize(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn reset_password(
+    CurrentStore(store): CurrentStore,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Json(body): Json<ResetPasswordRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let store_id = store.object_id.unwrap();
+    let invalid_link = || AppError::Validation(Message::new("INVALID_RESET_LINK"));
+
+    validate_new_password(&body.password, &body.confirm_password)?;
+
+    let reset_token = mongo_repo
+        .take_password_reset_token(&store_id, &hash_token(&body.token))
+        .await?
+        .ok_or_else(invalid_link)?;
+
+    let hashed_password = hash(&body.password, DEFAULT_COST)?;
+
+    if !mongo_repo
+        .update_user_password(&store_id, &reset_token.user_id, &hashed_password)
+        .await?
+    {
+        return Err(invalid_link());
+    }
+    revoke_all_sessions(&mongo_repo, store_id, reset_token.user_id).await?;
+
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("PASSWORD_CHANGED").localize(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn setup_two_factor(
+    AuthUser { store, user, .. }: AuthUser,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    let secret = generate_secret();
+
+    if !mongo_repo
+        .set_totp_pending_secret(&store.object_id.unwrap(), &user.object_id.unwrap(), &STOCK_CIPHER.encrypt_secret(&secret))
+        .await?
+    {
+        return Err(AppError::Conflict(Message::new("TWO_FACTOR_ALREADY_ENABLED")));
+    }
+
+    let response = TwoFactorSetupResponse {
+        status: Status::Success,
+        otpauth_uri: otpauth_uri(&store.name, &user.user_id, &secret),
+        secret,
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn confirm_two_factor(
+    AuthUser { store, user, .. }: AuthUser,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Json(body): Json<TwoFactorCodeRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let stored_secret = user
+        .totp_pending_secret
+        .as_deref()
+        .filter(|_| !user.totp_enabled)
+        .ok_or_else(|| AppError::Validation(Message::new("TWO_FACTOR_SETUP_NOT_STARTED")))?;
+    let secret = STOCK_CIPHER.decrypt_secret(stored_secret)?;
+
+    let step = verify_code(&secret, &body.code).ok_or_else(invalid_code)?;
+
+    let recovery_codes = generate_recovery_codes();
+    let recovery_hashes: Vec<String> = recovery_codes.iter().map(|code| hash_recovery_code(code)).collect();
+
+    if !mongo_repo
+        .enable_totp(&store.object_id.unwrap(), &user.object_id.unwrap(), stored_secret, step, &recovery_hashes)
+        .await?
+    {
+        return Err(AppError::Conflict(Message::new("TWO_FACTOR_SETUP_NOT_STARTED")));
+    }
+
+    let response = RecoveryCodesResponse {
+        status: Status::Success,
+        recovery_codes,
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn disable_two_factor(
+    AuthUser { store, user, .. }: AuthUser,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Json(body): Json<TwoFactorCodeRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if !user.totp_enabled {
+        return Err(AppError::Validation(Message::new("TWO_FACTOR_NOT_ENABLED")));
+    }
+
+    let permissions = effective_permissions(&mongo_repo, &store.object_id.unwrap(), &user).await?;
+    if store.require_admin_2fa && !permissions.is_empty() {
+        return Err(AppError::Forbidden(Message::new("ADMIN_TWO_FACTOR_LOCKED")));
+    }
+
+    if !verify_second_factor(&mongo_repo, &user, &body.code).await? {
+        return Err(invalid_code());
+    }
+    mongo_repo
+        .disable_totp(&store.object_id.unwrap(), &user.object_id.unwrap())
+        .await?;
+
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("TWO_FACTOR_DISABLED").localize(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn login_two_factor(
+    CurrentStore(store): CurrentStore,
+    ClientIp(ip): ClientIp,
+    client: SessionClient,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Json(body): Json<TwoFactorLoginRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let store_id = store.object_id.unwrap();
+
+    let user_id = validate_challenge_jwt(&body.challenge_token)
+        .filter(|claims| claims.store_id == store_id.to_hex())
+        .and_then(|claims| ObjectId::from_str(&claims.id).ok());
+
+    let user = match user_id {
+        Some(user_id) => mongo_repo.find_user_by_id(&store_id, &user_id).await?,
+        None => None,
+    };
+
+    let user = user.filter(|user| user.totp_enabled).ok_or_else(|| {
+        AppError::Unauthorized(Message::new("INVALID_TWO_FACTOR_CHALLENGE"))
+    })?;
+
+    let attempt = ensure_login_allowed(&mongo_repo, &store_id, login_attempt_keys(&user.user_id, ip)).await?;
+    ensure_not_suspended(&user)?;
+
+    if !verify_second_factor(&mongo_repo, &user, &body.code).await? {
+        attempt.fail().await;
+        return Err(invalid_code());
+    }
+
+    attempt.succeed().await;
+    let user_id = user.object_id.unwrap();
+    let session_id = start_session(&mongo_repo, store_id, user_id, client).await?;
+    issue_token_response(&mongo_repo, user_id, store_id, Some(session_id)).await
+}
+
+pub async fn unlock_user(
+    Authorized { store, .. }: Authorized,
+    Path(user_id): Path<String>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    let store_id = store.object_id.unwrap();
+
+    if mongo_repo.find_user_by_user_id(&store_id, &user_id).await?.is_none() {
+        return Err(AppError::NotFound(Message::new("USER_NOT_FOUND")));
+    }
+
+    mongo_repo.clear_login_failures(&store_id, &LoginAttempt::account_key(&user_id)).await?;
+
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("USER_UNLOCKED").arg(user_id).localize(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn list_api_keys(
+    Authorized { store, .. }: Authorized,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    let api_keys = mongo_repo.find_api_keys(&store.object_id.unwrap()).await?;
+
+    let response = ApiKeyListResponse {
+        status: Status::Success,
+        api_keys: api_keys.iter().map(Into::into).collect(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn create_api_key(
+    Authorized { store, user, permissions: granter }: Authorized,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Json(body): Json<CreateApiKeyRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let name = body.name.trim().to_string();
+    if name.is_empty() || name.chars().count() > MAX_API_KEY_NAME_LENGTH {
+        return Err(AppError::Validation(Message::new("INVALID_API_KEY_NAME")));
+    }
+
+    let mut scopes = body.scopes;
+    scopes.sort();
+    scopes.dedup();
+    if scopes.is_empty() || scopes.contains(&Permission::ApiKeysManage) {
+        return Err(AppError::Validation(Message::new("INVALID_API_KEY_SCOPES")));
+    }
+    ensure_can_grant(&granter, &scopes)?;
+
+    if body.expires_at.is_some_and(|expires_at| expires_at <= Utc::now()) {
+        return Err(AppError::Validation(Message::new("INVALID_API_KEY_EXPIRY")));
+    }
+
+    let key = generate_api_key();
+    let mut api_key = ApiKey::new(
+        store.object_id.unwrap(),
+        user.object_id.unwrap(),
+        name,
+        &key,
+        hash_token(&key),
+        scopes,
+        body.expires_at,
+    );
+    api_key.object_id = Some(mongo_repo.create_api_key(api_key.clone()).await?);
+
+    let response = ApiKeyCreatedResponse {
+        status: Status::Success,
+        api_key: (&api_key).into(),
+        key,
+    };
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+pub async fn revoke_api_key(
+    Authorized { store, .. }: Authorized,
+    Path(key_id): Path<String>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    let key_id = parse_object_id(&key_id, "INVALID_API_KEY_ID")?;
+
+    if !mongo_repo.delete_api_key(&store.object_id.unwrap(), &key_id).await? {
+        return Err(AppError::NotFound(Message::new("API_KEY_NOT_FOUND")));
+    }
+
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("API_KEY_REVOKED").localize(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+fn invalid_code() -> AppError {
+    AppError::Unauthorized(Message::new("INVALID_TWO_FACTOR_CODE"))
+}
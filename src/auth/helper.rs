@@ -0,0 +1,353 @@
+use std::collections::BTreeSet;
+use std::net::IpAddr;
+use std::sync::Arc;
+use axum::http::{HeaderMap, StatusCode};
+use axum::Json;
+use axum::response::{IntoResponse, Response};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use bcrypt::{hash, DEFAULT_COST};
+use bson::oid::ObjectId;
+use chrono::Utc;
+use hkdf::Hkdf;
+use hmac::{Hmac, Mac};
+use once_cell::sync::Lazy;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use crate::auth::extractor::{Caller, SessionClient};
+use crate::auth::model::{LoginAttempt, PasswordResetToken, RefreshToken, RevokedToken, Session};
+use crate::common::crypto::STOCK_CIPHER;
+use crate::common::email::{escape_html, send_email, Mailer};
+use crate::common::error::AppError;
+use crate::common::i18n::{requested_locale_or, Message};
+use crate::common::jwt::generate_jwt;
+use crate::common::totp::verify_code;
+use crate::common::types::Status;
+use crate::config::CONFIG;
+use crate::database::MongoRepository;
+use crate::role::helper::effective_permissions;
+use crate::role::model::Permission;
+use crate::store::model::Store;
+use crate::user::helper::ensure_not_suspended;
+use crate::user::model::User;
+use crate::user::model::UserResponse;
+
+pub struct IssuedTokens {
+    pub access_token: String,
+    pub refresh_token: String,
+}
+
+pub fn generate_secret_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+pub fn hash_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
+
+pub const API_KEY_HEADER: &str = "X-Api-Key";
+const API_KEY_PREFIX: &str = "vxk_";
+
+pub fn generate_api_key() -> String {
+    format!("{}{}", API_KEY_PREFIX, generate_secret_token())
+}
+
+// API 키는 만든 관리자를 대신해 동작하므로, 키의 범위와 만든 사람의 현재 권한이 겹치는 부분만 허용합니다.
+pub async fn resolve_api_key(
+    mongo_repo: &Arc<MongoRepository>,
+    headers: &HeaderMap,
+    store: Store,
+) -> Result<Caller, AppError> {
+    let key = headers
+        .get(API_KEY_HEADER)
+        .and_then(|h| h.to_str().ok())
+        .map(str::trim)
+        .ok_or_else(|| AppError::Unauthorized(Message::new("INVALID_API_KEY")))?;
+
+    let store_id = store.object_id.unwrap();
+    let api_key = mongo_repo
+        .use_api_key(&store_id, &hash_token(key))
+        .await?
+        .ok_or_else(|| AppError::Unauthorized(Message::new("INVALID_API_KEY")))?;
+
+    let user = mongo_repo
+        .find_user_by_id(&store_id, &api_key.created_by)
+        .await?
+        .ok_or_else(|| AppError::Unauthorized(Message::new("INVALID_API_KEY")))?;
+    ensure_not_suspended(&user)?;
+
+    let permissions: BTreeSet<Permission> = effective_permissions(mongo_repo, &store_id, &user)
+        .await?
+        .into_iter()
+        .filter(|granted| api_key.scopes.contains(granted))
+        .collect();
+
+    Ok(Caller { store, user, permissions, via_api_key: true })
+}
+
+pub async fn issue_tokens(
+    mongo_repo: &Arc<MongoRepository>,
+    user_id: ObjectId,
+    store_id: ObjectId,
+    family_id: Option<ObjectId>,
+) -> mongodb::error::Result<IssuedTokens> {
+    let refresh_token = generate_secret_token();
+    let family_id = family_id.unwrap_or_default();
+
+    mongo_repo
+        .create_refresh_token(RefreshToken::new(store_id, user_id, family_id, hash_token(&refresh_token)))
+        .await?;
+
+    Ok(IssuedTokens {
+        access_token: generate_jwt(user_id, store_id, family_id, &CONFIG.jwt_secret),
+        refresh_token,
+    })
+}
+
+pub async fn issue_token_response(
+    mongo_repo: &Arc<MongoRepository>,
+    user_id: ObjectId,
+    store_id: ObjectId,
+    family_id: Option<ObjectId>,
+) -> Result<Response, AppError> {
+    let tokens = issue_tokens(mongo_repo, user_id, store_id, family_id).await?;
+
+    let response = UserResponse {
+        status: Status::Success,
+        token: tokens.access_token,
+        refresh_token: tokens.refresh_token,
+    };
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+pub async fn revoke_all_sessions(
+    mongo_repo: &Arc<MongoRepository>,
+    store_id: ObjectId,
+    user_id: ObjectId,
+) -> mongodb::error::Result<()> {
+    mongo_repo.revoke_user_refresh_tokens(&store_id, &user_id).await?;
+    mongo_repo.delete_user_sessions(&store_id, &user_id).await?;
+    mongo_repo.revoke_access_token(RevokedToken::all(store_id, user_id)).await
+}
+
+pub async fn start_session(
+    mongo_repo: &Arc<MongoRepository>,
+    store_id: ObjectId,
+    user_id: ObjectId,
+    client: SessionClient,
+) -> mongodb::error::Result<ObjectId> {
+    let session = Session::new(store_id, user_id, client.user_agent, client.ip);
+    let session_id = session.object_id;
+    mongo_repo.create_session(session).await?;
+    Ok(session_id)
+}
+
+// 세션 하나만 끊을 때는 리프레시 토큰 패밀리와 함께 그 세션의 sid로 발급된 액세스 토큰도 막습니다.
+pub async fn revoke_session(
+    mongo_repo: &Arc<MongoRepository>,
+    store_id: ObjectId,
+    user_id: ObjectId,
+    session_id: ObjectId,
+) -> mongodb::error::Result<bool> {
+    if !mongo_repo.delete_session(&store_id, &user_id, &session_id).await? {
+        return Ok(false);
+    }
+
+    mongo_repo.revoke_refresh_token_family(&session_id).await?;
+    mongo_repo
+        .revoke_access_token(RevokedToken::session(store_id, user_id, session_id.to_hex()))
+        .await?;
+    Ok(true)
+}
+
+pub fn spawn_password_reset_email(
+    mongo_repo: Arc<MongoRepository>,
+    mailer: Arc<dyn Mailer>,
+    store: Store,
+    email: String,
+) {
+    let locale = requested_locale_or(store.default_locale);
+    tokio::spawn(async move {
+        let store_id = store.object_id.unwrap();
+        let user = match mongo_repo.find_user_by_email(&store_id, &email).await {
+            Ok(Some(user)) => user,
+            Ok(None) => return,
+            Err(err) => {
+                println!("비밀번호 재설정 대상 조회 실패 ('{}'): {}", email, err);
+                return;
+            }
+        };
+
+        let token = generate_secret_token();
+        let reset_token = PasswordResetToken::new(store_id, user.object_id.unwrap(), hash_token(&token));
+        if let Err(err) = mongo_repo.replace_password_reset_token(reset_token).await {
+            println!("비밀번호 재설정 토큰 저장 실패 ('{}'): {}", email, err);
+            return;
+        }
+
+        let link = format!("{}?token={}", CONFIG.password_reset_url, token);
+        let subject = Message::new("MAIL_RESET_SUBJECT").arg(&store.name).render(locale);
+        let body = format!(
+            r#"<div style="font-family: sans-serif;">
+  <h2>{}</h2>
+  <p>{}</p>
+  <p><a href="{}">{}</a></p>
+  <p>{} {}</p>
+</div>"#,
+            Message::new("MAIL_RESET_TITLE").render(locale),
+            Message::new("MAIL_RESET_BODY").arg(escape_html(&user.user_id)).render(locale),
+            escape_html(&link),
+            Message::new("MAIL_RESET_LINK").render(locale),
+            Message::new("MAIL_RESET_VALID_MINUTES").arg(CONFIG.password_reset_ttl_secs / 60).render(locale),
+            Message::new("MAIL_IGNORE_IF_NOT_REQUESTED").render(locale),
+        );
+
+        if let Err(err) = send_email(&mailer, &store.sender(), vec![user.email], &subject, &body).await {
+            println!("비밀번호 재설정 메일 발송 실패 ('{}'): {}", email, err);
+        }
+    });
+}
+
+const RECOVERY_CODE_COUNT: usize = 10;
+const RECOVERY_CODE_ALPHABET: &[u8] = b"abcdefghjkmnpqrstuvwxyz23456789";
+
+pub fn generate_recovery_codes() -> Vec<String> {
+    let mut rng = rand::thread_rng();
+    (0..RECOVERY_CODE_COUNT)
+        .map(|_| {
+            let chars: String = (0..8)
+                .map(|_| RECOVERY_CODE_ALPHABET[(rng.next_u32() as usize) % RECOVERY_CODE_ALPHABET.len()] as char)
+                .collect();
+            format!("{}-{}", &chars[..4], &chars[4..])
+        })
+        .collect()
+}
+
+// 복구 코드는 짧아서 평문 해시로는 DB 가 유출되면 쉽게 역산되므로 JWT_SECRET 에서 유도한 서버 키로 HMAC 을 계산해 저장합니다.
+static RECOVERY_CODE_KEY: Lazy<[u8; 32]> = Lazy::new(|| {
+    let mut key = [0u8; 32];
+    Hkdf::<Sha256>::new(None, CONFIG.jwt_secret.as_bytes())
+        .expand(b"recovery-code", &mut key)
+        .expect("HKDF 출력 길이가 올바르지 않습니다.");
+    key
+});
+
+pub fn hash_recovery_code(code: &str) -> String {
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(&*RECOVERY_CODE_KEY).expect("HMAC은 모든 키 길이를 허용합니다.");
+    mac.update(code.trim().to_lowercase().as_bytes());
+    format!("{:x}", mac.finalize().into_bytes())
+}
+
+// 암호화 전에 저장된 평문 TOTP 시크릿도 그대로 읽힙니다.
+pub async fn verify_second_factor(
+    mongo_repo: &Arc<MongoRepository>,
+    user: &User,
+    code: &str,
+) -> Result<bool, AppError> {
+    let (Some(store_id), Some(user_id), Some(secret)) = (user.store_id, user.object_id, user.totp_secret.as_deref()) else {
+        return Ok(false);
+    };
+    let secret = STOCK_CIPHER.decrypt_secret(secret)?;
+
+    if let Some(step) = verify_code(&secret, code) {
+        return Ok(mongo_repo.claim_totp_step(&store_id, &user_id, step).await?);
+    }
+
+    Ok(mongo_repo.use_recovery_code(&store_id, &user_id, &hash_recovery_code(code)).await?)
+}
+
+// 존재하지 않는 아이디로 로그인할 때도 bcrypt 검증을 한 번 수행해 응답 시간으로 계정 존재 여부가 드러나지 않게 합니다.
+pub static DUMMY_PASSWORD_HASH: Lazy<String> = Lazy::new(|| {
+    hash(generate_secret_token(), DEFAULT_COST).expect("더미 비밀번호 해시 생성에 실패하였습니다.")
+});
+
+pub fn login_attempt_keys(login_id: &str, ip: IpAddr) -> [String; 2] {
+    [LoginAttempt::account_key(login_id), LoginAttempt::ip_key(ip)]
+}
+
+// 확인과 예약을 한 번의 갱신으로 처리해 동시에 들어온 시도가 잠금 한도를 넘지 못하게 합니다.
+// fail/succeed 없이 버려진 예약은 Drop 에서 풀어줍니다.
+pub struct LoginAttemptReservation {
+    mongo_repo: Arc<MongoRepository>,
+    store_id: ObjectId,
+    keys: Vec<(String, i64)>,
+}
+
+impl LoginAttemptReservation {
+    pub async fn fail(mut self) {
+        for (key, max_failures) in std::mem::take(&mut self.keys) {
+            if let Err(err) = self.mongo_repo.record_login_failure(&self.store_id, &key, max_failures).await {
+                println!("로그인 실패 기록 저장 실패 ('{}'): {}", key, err);
+            }
+        }
+    }
+
+    pub async fn succeed(mut self) {
+        for (key, _) in std::mem::take(&mut self.keys) {
+            let result = if LoginAttempt::is_account_key(&key) {
+                self.mongo_repo.clear_login_failures(&self.store_id, &key).await.map(|_| ())
+            } else {
+                self.mongo_repo.release_login_attempt(&self.store_id, &key).await
+            };
+
+            if let Err(err) = result {
+                println!("로그인 실패 기록 초기화 실패 ('{}'): {}", key, err);
+            }
+        }
+    }
+}
+
+impl Drop for LoginAttemptReservation {
+    fn drop(&mut self) {
+        if self.keys.is_empty() {
+            return;
+        }
+
+        let mongo_repo = self.mongo_repo.clone();
+        let store_id = self.store_id;
+        let keys = std::mem::take(&mut self.keys);
+        tokio::spawn(async move {
+            for (key, _) in keys {
+                if let Err(err) = mongo_repo.release_login_attempt(&store_id, &key).await {
+                    println!("로그인 시도 예약 해제 실패 ('{}'): {}", key, err);
+                }
+            }
+        });
+    }
+}
+
+pub async fn ensure_login_allowed(
+    mongo_repo: &Arc<MongoRepository>,
+    store_id: &ObjectId,
+    keys: [String; 2],
+) -> Result<LoginAttemptReservation, AppError> {
+    let mut reservation = LoginAttemptReservation {
+        mongo_repo: mongo_repo.clone(),
+        store_id: *store_id,
+        keys: Vec::with_capacity(keys.len()),
+    };
+
+    let now = Utc::now();
+    let mut blocked_for = None;
+    for (key, max_failures) in keys.into_iter().zip([CONFIG.login_max_failures, CONFIG.login_ip_max_failures]) {
+        let attempt = mongo_repo.reserve_login_attempt(store_id, &key).await?;
+        reservation.keys.push((key, max_failures));
+
+        let remaining = attempt.and_then(|attempt| attempt.blocked_for(max_failures, now));
+        blocked_for = blocked_for.max(remaining);
+    }
+
+    match blocked_for {
+        None => Ok(reservation),
+        Some(remaining) => Err(AppError::TooManyRequests {
+            message: Message::new("LOGIN_LOCKED").arg(remaining),
+            retry_after: Some(remaining),
+        }),
+    }
+}
+
+pub fn invalid_credentials() -> AppError {
+    AppError::Unauthorized(Message::new("INVALID_CREDENTIALS"))
+}
@@ -0,0 +1,5 @@
+pub mod model;
+pub mod repository;
+pub mod handler;
+pub mod helper;
+pub mod extractor;pub mod policy;
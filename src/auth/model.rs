@@ -0,0 +1,374 @@
+use std::net::IpAddr;
+use bson::oid::ObjectId;
+use bson::serde_helpers::{chrono_datetime_as_bson_datetime, chrono_datetime_as_bson_datetime_optional};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use crate::common::types::Status;
+use crate::role::model::Permission;
+use crate::config::CONFIG;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RefreshToken {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<ObjectId>,
+    pub store_id: ObjectId,
+    pub user_id: ObjectId,
+    pub family_id: ObjectId,
+    pub token_hash: String,
+    pub used: bool,
+    pub revoked: bool,
+    #[serde(with = "chrono_datetime_as_bson_datetime")]
+    pub expires_at: DateTime<Utc>,
+    #[serde(with = "chrono_datetime_as_bson_datetime")]
+    pub create_at: DateTime<Utc>,
+}
+
+impl RefreshToken {
+    pub fn new(store_id: ObjectId, user_id: ObjectId, family_id: ObjectId, token_hash: String) -> Self {
+        let now = Utc::now();
+        RefreshToken {
+            object_id: None,
+            store_id,
+            user_id,
+            family_id,
+            token_hash,
+            used: false,
+            revoked: false,
+            expires_at: now + Duration::seconds(CONFIG.refresh_token_ttl_secs),
+            create_at: now,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RevokedToken {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<ObjectId>,
+    pub store_id: ObjectId,
+    pub user_id: ObjectId,
+    pub jti: Option<String>,
+    #[serde(default)]
+    pub sid: Option<String>,
+    #[serde(default, with = "chrono_datetime_as_bson_datetime_optional")]
+    pub revoked_before: Option<DateTime<Utc>>,
+    #[serde(with = "chrono_datetime_as_bson_datetime")]
+    pub expires_at: DateTime<Utc>,
+}
+
+impl RevokedToken {
+    pub fn single(store_id: ObjectId, user_id: ObjectId, jti: String, expires_at: DateTime<Utc>) -> Self {
+        RevokedToken {
+            object_id: None,
+            store_id,
+            user_id,
+            jti: Some(jti),
+            sid: None,
+            revoked_before: None,
+            expires_at,
+        }
+    }
+
+    pub fn session(store_id: ObjectId, user_id: ObjectId, sid: String) -> Self {
+        RevokedToken {
+            object_id: None,
+            store_id,
+            user_id,
+            jti: None,
+            sid: Some(sid),
+            revoked_before: None,
+            expires_at: Utc::now() + Duration::seconds(CONFIG.access_token_ttl_secs),
+        }
+    }
+
+    pub fn all(store_id: ObjectId, user_id: ObjectId) -> Self {
+        let now = Utc::now();
+        RevokedToken {
+            object_id: None,
+            store_id,
+            user_id,
+            jti: None,
+            sid: None,
+            revoked_before: Some(now),
+            expires_at: now + Duration::seconds(CONFIG.access_token_ttl_secs),
+        }
+    }
+}
+
+// 세션 ID는 리프레시 토큰 패밀리 ID와 같으며 액세스 토큰의 sid 클레임에도 담깁니다.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Session {
+    #[serde(rename = "_id")]
+    pub object_id: ObjectId,
+    pub store_id: ObjectId,
+    pub user_id: ObjectId,
+    #[serde(default)]
+    pub user_agent: Option<String>,
+    #[serde(default)]
+    pub ip: Option<String>,
+    #[serde(with = "chrono_datetime_as_bson_datetime")]
+    pub create_at: DateTime<Utc>,
+    #[serde(with = "chrono_datetime_as_bson_datetime")]
+    pub last_seen_at: DateTime<Utc>,
+    #[serde(with = "chrono_datetime_as_bson_datetime")]
+    pub expires_at: DateTime<Utc>,
+}
+
+impl Session {
+    pub fn new(store_id: ObjectId, user_id: ObjectId, user_agent: Option<String>, ip: Option<IpAddr>) -> Self {
+        let now = Utc::now();
+        Session {
+            object_id: ObjectId::new(),
+            store_id,
+            user_id,
+            user_agent,
+            ip: ip.map(|ip| ip.to_string()),
+            create_at: now,
+            last_seen_at: now,
+            expires_at: now + Duration::seconds(CONFIG.refresh_token_ttl_secs),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PublicSession {
+    pub id: String,
+    pub user_agent: Option<String>,
+    pub ip: Option<String>,
+    pub create_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    pub current: bool,
+}
+
+impl PublicSession {
+    pub fn new(session: Session, current_sid: Option<&str>) -> Self {
+        let id = session.object_id.to_hex();
+        PublicSession {
+            current: current_sid == Some(id.as_str()),
+            id,
+            user_agent: session.user_agent,
+            ip: session.ip,
+            create_at: session.create_at,
+            last_seen_at: session.last_seen_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SessionListResponse {
+    pub status: Status,
+    pub sessions: Vec<PublicSession>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PasswordResetToken {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<ObjectId>,
+    pub store_id: ObjectId,
+    pub user_id: ObjectId,
+    pub token_hash: String,
+    #[serde(with = "chrono_datetime_as_bson_datetime")]
+    pub expires_at: DateTime<Utc>,
+    #[serde(with = "chrono_datetime_as_bson_datetime")]
+    pub create_at: DateTime<Utc>,
+}
+
+impl PasswordResetToken {
+    pub fn new(store_id: ObjectId, user_id: ObjectId, token_hash: String) -> Self {
+        let now = Utc::now();
+        PasswordResetToken {
+            object_id: None,
+            store_id,
+            user_id,
+            token_hash,
+            expires_at: now + Duration::seconds(CONFIG.password_reset_ttl_secs),
+            create_at: now,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RefreshRequest {
+    pub refresh_token: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ForgotPasswordRequest {
+    pub email: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ResetPasswordRequest {
+    pub token: String,
+    pub password: String,
+    pub confirm_password: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TwoFactorChallengeResponse {
+    pub status: Status,
+    pub two_factor_required: bool,
+    pub challenge_token: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TwoFactorSetupResponse {
+    pub status: Status,
+    pub secret: String,
+    pub otpauth_uri: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RecoveryCodesResponse {
+    pub status: Status,
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TwoFactorCodeRequest {
+    pub code: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TwoFactorLoginRequest {
+    pub challenge_token: String,
+    pub code: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LoginAttempt {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<ObjectId>,
+    pub store_id: ObjectId,
+    pub key: String,
+    pub failures: i64,
+    #[serde(default)]
+    pub in_flight: i64,
+    #[serde(default, with = "chrono_datetime_as_bson_datetime_optional")]
+    pub locked_until: Option<DateTime<Utc>>,
+    #[serde(with = "chrono_datetime_as_bson_datetime")]
+    pub expires_at: DateTime<Utc>,
+}
+
+impl LoginAttempt {
+    pub fn account_key(login_id: &str) -> String {
+        format!("account:{}", login_id.trim().to_lowercase())
+    }
+
+    pub fn ip_key(ip: IpAddr) -> String {
+        format!("ip:{}", ip)
+    }
+
+    pub fn is_account_key(key: &str) -> bool {
+        key.starts_with("account:")
+    }
+
+    // in_flight 는 이번 시도를 포함한 값입니다. 잠금이 풀린 뒤에는 한 번에 한 시도만 허용합니다.
+    pub fn blocked_for(&self, max_failures: i64, now: DateTime<Utc>) -> Option<i64> {
+        if let Some(locked_until) = self.locked_until.filter(|locked_until| *locked_until > now) {
+            return Some((locked_until - now).num_seconds().max(1));
+        }
+
+        let allowance = (max_failures - self.failures).max(1);
+        (self.in_flight > allowance).then_some(1)
+    }
+
+    pub fn lock_duration(failures: i64, max_failures: i64) -> Option<Duration> {
+        if failures < max_failures {
+            return None;
+        }
+
+        let exponent = (failures - max_failures).min(20) as u32;
+        let secs = CONFIG.login_lockout_base_secs.saturating_mul(1 << exponent);
+        Some(Duration::seconds(secs.min(CONFIG.login_lockout_max_secs)))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ApiKey {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<ObjectId>,
+    pub store_id: ObjectId,
+    pub created_by: ObjectId,
+    pub name: String,
+    pub key_prefix: String,
+    pub key_hash: String,
+    pub scopes: Vec<Permission>,
+    #[serde(default, with = "chrono_datetime_as_bson_datetime_optional")]
+    pub expires_at: Option<DateTime<Utc>>,
+    #[serde(default, with = "chrono_datetime_as_bson_datetime_optional")]
+    pub last_used_at: Option<DateTime<Utc>>,
+    #[serde(with = "chrono_datetime_as_bson_datetime")]
+    pub create_at: DateTime<Utc>,
+}
+
+impl ApiKey {
+    pub fn new(
+        store_id: ObjectId,
+        created_by: ObjectId,
+        name: String,
+        key: &str,
+        key_hash: String,
+        scopes: Vec<Permission>,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        ApiKey {
+            object_id: None,
+            store_id,
+            created_by,
+            name,
+            key_prefix: key.chars().take(API_KEY_PREFIX_LENGTH).collect(),
+            key_hash,
+            scopes,
+            expires_at,
+            last_used_at: None,
+            create_at: Utc::now(),
+        }
+    }
+}
+
+pub const API_KEY_PREFIX_LENGTH: usize = 11;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CreateApiKeyRequest {
+    pub name: String,
+    pub scopes: Vec<Permission>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PublicApiKey {
+    pub id: Option<String>,
+    pub name: String,
+    pub key_prefix: String,
+    pub scopes: Vec<Permission>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub create_at: DateTime<Utc>,
+}
+
+impl From<&ApiKey> for PublicApiKey {
+    fn from(api_key: &ApiKey) -> Self {
+        PublicApiKey {
+            id: api_key.object_id.map(|id| id.to_hex()),
+            name: api_key.name.clone(),
+            key_prefix: api_key.key_prefix.clone(),
+            scopes: api_key.scopes.clone(),
+            expires_at: api_key.expires_at,
+            last_used_at: api_key.last_used_at,
+            create_at: api_key.create_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ApiKeyListResponse {
+    pub status: Status,
+    pub api_keys: Vec<PublicApiKey>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ApiKeyCreatedResponse {
+    pub status: Status,
+    pub api_key: PublicApiKey,
+    pub key: String,
+}
@@ -0,0 +1,465 @@
+use axum::extract::{FromRequestParts, MatchedPath, Request};
+use axum::handler::Handler;
+use axum::http::Method;
+use axum::middleware::Next;
+use axum::response::Response;
+use axum::routing::{on, MethodFilter};
+use axum::Router;
+use crate::auth::extractor::{AuthUser, Caller};
+use crate::auth::helper::API_KEY_HEADER;
+use crate::common::error::AppError;
+use crate::common::i18n::Message;
+use crate::role::helper::require_permission;
+use crate::role::model::Permission;
+use crate::user::helper::validate_security_key;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Access {
+    Public,
+    SecurityKey,
+    Authenticated,
+    Permission(Permission),
+}
+
+// 모든 라우트의 접근 권한을 한곳에 모아 둔 표입니다. 라우트는 policy_route 로 이 표에 있는 항목만 등록할 수 있습니다.
+pub const ROUTE_POLICIES: &[(&str, &str, Access)] = &[
+    // 스토어
+    ("POST", "/api/store/:store_name", Access::SecurityKey),
+    ("DELETE", "/api/store/:store_name", Access::SecurityKey),
+    ("PATCH", "/api/store/:store_name/:new_store_name", Access::SecurityKey),
+    ("PATCH", "/api/store/:store_name/settings/email", Access::SecurityKey),
+    ("PATCH", "/api/store/:store_name/settings/verification", Access::SecurityKey),
+    ("PATCH", "/api/store/:store_name/settings/two-factor", Access::SecurityKey),
+    ("PATCH", "/api/store/:store_name/settings/locale", Access::SecurityKey),
+    ("POST", "/api/store/:store_name/administrator", Access::SecurityKey),
+    // 인증
+    ("POST", "/api/auth/register", Access::Public),
+    ("POST", "/api/auth/login", Access::Public),
+    ("POST", "/api/auth/email/verify", Access::Public),
+    ("POST", "/api/auth/email/resend", Access::Authenticated),
+    ("POST", "/api/auth/refresh", Access::Public),
+    ("POST", "/api/auth/logout", Access::Authenticated),
+    ("POST", "/api/auth/logout/all", Access::Authenticated),
+    ("POST", "/api/auth/password/forgot", Access::Public),
+    ("POST", "/api/auth/password/reset", Access::Public),
+    ("POST", "/api/auth/2fa/setup", Access::Authenticated),
+    ("POST", "/api/auth/2fa/confirm", Access::Authenticated),
+    ("POST", "/api/auth/2fa/disable", Access::Authenticated),
+    ("POST", "/api/auth/2fa/login", Access::Public),
+    // 유저
+    ("GET", "/api/users/me", Access::Authenticated),
+    ("PATCH", "/api/users/me", Access::Authenticated),
+    ("DELETE", "/api/users/me", Access::Authenticated),
+    ("POST", "/api/users/me/password", Access::Authenticated),
+    ("GET", "/api/users/me/sessions", Access::Authenticated),
+    ("DELETE", "/api/users/me/sessions/:session_id", Access::Authenticated),
+    ("GET", "/api/admin/users", Access::Permission(Permission::UsersRead)),
+    ("PATCH", "/api/admin/users/:user_id/rank", Access::Permission(Permission::UsersManage)),
+    ("PUT", "/api/admin/users/:user_id/roles", Access::Permission(Permission::RolesManage)),
+    ("POST", "/api/admin/users/:user_id/suspend", Access::Permission(Permission::UsersManage)),
+    ("POST", "/api/admin/users/:user_id/unsuspend", Access::Permission(Permission::UsersManage)),
+    ("GET", "/api/admin/users/:user_id/sessions", Access::Permission(Permission::UsersRead)),
+    ("DELETE", "/api/admin/users/:user_id/sessions/:session_id", Access::Permission(Permission::UsersManage)),
+    ("POST", "/api/admin/users/:user_id/unlock", Access::Permission(Permission::UsersManage)),
+    ("GET", "/api/admin/api-keys", Access::Permission(Permission::ApiKeysManage)),
+    ("POST", "/api/admin/api-keys", Access::Permission(Permission::ApiKeysManage)),
+    ("DELETE", "/api/admin/api-keys/:key_id", Access::Permission(Permission::ApiKeysManage)),
+    // 권한, 역할
+    ("GET", "/api/admin/roles", Access::Permission(Permission::RolesManage)),
+    ("POST", "/api/admin/roles", Access::Permission(Permission::RolesManage)),
+    ("PATCH", "/api/admin/roles/:role_name", Access::Permission(Permission::RolesManage)),
+    ("DELETE", "/api/admin/roles/:role_name", Access::Permission(Permission::RolesManage)),
+    // 카테고리, 제품
+    ("GET", "/api/category", Access::Public),
+    ("POST", "/api/category", Access::Permission(Permission::CategoryWrite)),
+    ("DELETE", "/api/category/:category_name", Access::Permission(Permission::CategoryDelete)),
+    ("GET", "/api/category/:category_name/product", Access::Public),
+    ("POST", "/api/category/:category_name/product", Access::Permission(Permission::ProductWrite)),
+    ("GET", "/api/category/:category_name/product/:product_name", Access::Public),
+    ("DELETE", "/api/category/:category_name/product/:product_name", Access::Permission(Permission::ProductDelete)),
+    ("POST", "/api/category/:category_name/product/:product_name/notify-me", Access::Authenticated),
+    ("DELETE", "/api/category/:category_name/product/:product_name/notify-me", Access::Authenticated),
+    // 재고
+    ("GET", "/api/category/:category_name/product/:product_name/stock", Access::Permission(Permission::StockRead)),
+    ("POST", "/api/category/:category_name/product/:product_name/stock", Access::Permission(Permission::StockWrite)),
+    ("DELETE", "/api/category/:category_name/product/:product_name/stock", Access::Permission(Permission::StockWrite)),
+    ("POST", "/api/category/:category_name/product/:product_name/stock/clear", Access::Permission(Permission::StockWrite)),
+    ("PATCH", "/api/category/:category_name/product/:product_name/stock/threshold", Access::Permission(Permission::StockWrite)),
+    ("POST", "/api/category/:category_name/product/:product_name/stock/import", Access::Permission(Permission::StockWrite)),
+    // 주문, 예약
+    ("POST", "/api/category/:category_name/product/:product_name/purchase", Access::Authenticated),
+    ("GET", "/api/orders", Access::Authenticated),
+    ("GET", "/api/orders/:order_id", Access::Authenticated),
+    ("POST", "/api/orders/:order_id/receipt", Access::Authenticated),
+    ("GET", "/api/admin/orders", Access::Permission(Permission::OrdersRead)),
+    ("POST", "/api/category/:category_name/product/:product_name/reserve", Access::Authenticated),
+    ("POST", "/api/reservations/:reservation_id/confirm", Access::Authenticated),
+    ("POST", "/api/reservations/:reservation_id/cancel", Access::Authenticated),
+];
+
+fn find_access(method: &Method, path: &str) -> Option<Access> {
+    let method = if method == Method::HEAD { Method::GET.as_str() } else { method.as_str() };
+    ROUTE_POLICIES
+        .iter()
+        .find(|(policy_method, policy_path, _)| *policy_method == method && *policy_path == path)
+        .map(|(_, _, access)| *access)
+}
+
+// 라우트는 정책 표에 있는 (메서드, 경로)로만 등록할 수 있습니다. 빠진 항목은 요청 때의 500 이 아니라
+// 라우터를 만들 때, 즉 서버 시작과 테스트에서 바로 드러납니다.
+pub trait PolicyRouter {
+    fn policy_route<H, T>(self, method: Method, path: &str, handler: H) -> Self
+    where
+        H: Handler<T, ()>,
+        T: 'static;
+}
+
+impl PolicyRouter for Router {
+    fn policy_route<H, T>(self, method: Method, path: &str, handler: H) -> Self
+    where
+        H: Handler<T, ()>,
+        T: 'static,
+    {
+        if find_access(&method, path).is_none() {
+            panic!("접근 권한이 등록되지 않은 라우트입니다: {} {}", method, path);
+        }
+
+        let filter = MethodFilter::try_from(method).expect("지원하지 않는 HTTP 메서드입니다.");
+        self.route(path, on(filter, handler))
+    }
+}
+
+// 라우팅이 끝난 뒤 표에 적힌 접근 권한을 검사하고, 통과한 인증 정보는 요청 확장에 남겨 핸들러가 다시 조회하지 않도록 합니다.
+pub async fn authorize_route(request: Request, next: Next) -> Result<Response, AppError> {
+    let path = request.extensions().get::<MatchedPath>().map(|path| path.as_str().to_string());
+    let Some(access) = path.as_deref().and_then(|path| find_access(request.method(), path)) else {
+        println!("접근 권한이 등록되지 않은 라우트입니다: {} {:?}", request.method(), path);
+        return Err(AppError::Internal(Message::new("ROUTE_POLICY_MISSING")));
+    };
+
+    let (mut parts, body) = request.into_parts();
+    match access {
+        Access::Public => {}
+        Access::SecurityKey => validate_security_key(&parts.headers)?,
+        // 로그인 토큰만 받는 라우트에 API 키가 오면 토큰 누락이 아니라 API 키 거부로 알려 줍니다.
+        Access::Authenticated if parts.headers.contains_key(API_KEY_HEADER) => {
+            return Err(AppError::Unauthorized(Message::new("API_KEY_NOT_ACCEPTED")));
+        }
+        Access::Authenticated => {
+            AuthUser::from_request_parts(&mut parts, &()).await?;
+        }
+        Access::Permission(permission) => {
+            let caller = Caller::from_request_parts(&mut parts, &()).await?;
+            parts.extensions.insert(require_permission(caller, permission)?);
+        }
+    }
+
+    Ok(next.run(Request::from_parts(parts, body)).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use axum::body::{to_bytes, Body};
+    use axum::http::StatusCode;
+    use axum::middleware;
+    use axum::Router;
+    use bson::oid::ObjectId;
+    use tower::ServiceExt;
+    use super::*;
+    use crate::app::{build_router, routes};
+    use crate::auth::extractor::CurrentStore;
+    use crate::common::email::{InMemoryMailer, Mailer};
+    use crate::common::error::AppErrorResponse;
+    use crate::common::jwt::{generate_jwt, Claims};
+    use crate::common::testing::init_config;
+    use crate::config::CONFIG;
+    use crate::database::MongoRepository;
+    use crate::store::model::Store;
+    use crate::user::model::{Rank, User};
+
+    const CUSTOM_ROLE_PERMISSIONS: [Permission; 3] = [Permission::StockRead, Permission::StockWrite, Permission::OrdersRead];
+    const API_KEY_PERMISSIONS: [Permission; 3] = [Permission::CategoryWrite, Permission::StockRead, Permission::ApiKeysManage];
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Principal {
+        Anonymous,
+        Customer,
+        Vip,
+        Administrator,
+        AdministratorWithoutTwoFactor,
+        CustomRole,
+        ApiKey,
+        SecurityKey,
+        WrongSecurityKey,
+    }
+
+    const PRINCIPALS: [Principal; 9] = [
+        Principal::Anonymous,
+        Principal::Customer,
+        Principal::Vip,
+        Principal::Administrator,
+        Principal::AdministratorWithoutTwoFactor,
+        Principal::CustomRole,
+        Principal::ApiKey,
+        Principal::SecurityKey,
+        Principal::WrongSecurityKey,
+    ];
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Outcome {
+        Passed,
+        Rejected(StatusCode, &'static str),
+    }
+
+    fn expected(access: Access, principal: Principal) -> Outcome {
+        use Outcome::{Passed, Rejected};
+        use Principal::*;
+
+        let unauthorized = |code| Rejected(StatusCode::UNAUTHORIZED, code);
+        let forbidden = |code| Rejected(StatusCode::FORBIDDEN, code);
+        match (access, principal) {
+            (Access::Public, _) => Passed,
+            (Access::SecurityKey, SecurityKey) => Passed,
+            (Access::SecurityKey, WrongSecurityKey) => unauthorized("INVALID_SECURITY_KEY"),
+            (Access::SecurityKey, _) => unauthorized("SECURITY_KEY_REQUIRED"),
+            (_, Anonymous | SecurityKey | WrongSecurityKey) => unauthorized("AUTHORIZATION_REQUIRED"),
+            (Access::Authenticated, ApiKey) => unauthorized("API_KEY_NOT_ACCEPTED"),
+            (Access::Authenticated, _) => Passed,
+            (Access::Permission(Permission::ApiKeysManage), ApiKey) => forbidden("API_KEY_NOT_ALLOWED"),
+            (Access::Permission(permission), ApiKey) if API_KEY_PERMISSIONS.contains(&permission) => Passed,
+            (Access::Permission(permission), CustomRole) if CUSTOM_ROLE_PERMISSIONS.contains(&permission) => Passed,
+            (Access::Permission(_), Administrator) => Passed,
+            (Access::Permission(_), AdministratorWithoutTwoFactor) => forbidden("ADMIN_TWO_FACTOR_REQUIRED"),
+            (Access::Permission(_), Customer | Vip | CustomRole | ApiKey) => forbidden("PERMISSION_DENIED"),
+        }
+    }
+
+    fn user(store: &Store, rank: Rank, roles: Vec<String>) -> User {
+        User {
+            object_id: Some(ObjectId::new()),
+            store_id: store.object_id,
+            user_id: "tester".to_string(),
+            email: "tester@example.com".to_string(),
+            pending_email: None,
+            password: String::new(),
+            rank,
+            roles,
+            verified: true,
+            suspended: false,
+            verification_sent_at: None,
+            totp_enabled: false,
+            totp_secret: None,
+            totp_pending_secret: None,
+            totp_last_step: None,
+            recovery_codes: Vec::new(),
+        }
+    }
+
+    fn auth_user(store: Store, user: User) -> AuthUser {
+        let claims = Claims {
+            id: user.object_id.unwrap().to_hex(),
+            store_id: store.object_id.unwrap().to_hex(),
+            sid: ObjectId::new().to_hex(),
+            jti: ObjectId::new().to_hex(),
+            iat: 0,
+            iat_ms: 0,
+            exp: usize::MAX / 2,
+        };
+        AuthUser { store, user, claims }
+    }
+
+    // 스토어, 유저, 역할, API 키 조회는 DB 가 필요하므로 추출기가 요청 확장에 남기는 결과를 미리 넣어 둡니다.
+    fn request(method: &str, path: &str, principal: Principal) -> Request {
+        let mut store = Store::new("acme".to_string());
+        store.object_id = Some(ObjectId::new());
+        if principal == Principal::AdministratorWithoutTwoFactor {
+            store.require_admin_2fa = true;
+        }
+
+        let uri: Vec<&str> = path.split('/').map(|segment| if segment.starts_with(':') { "x" } else { segment }).collect();
+        let mut request = Request::builder().method(method).uri(uri.join("/"));
+        match principal {
+            Principal::ApiKey => request = request.header(API_KEY_HEADER, "vxk_test"),
+            Principal::SecurityKey => request = request.header("X-Vronix-Security", CONFIG.vronix_security_key.as_str()),
+            Principal::WrongSecurityKey => request = request.header("X-Vronix-Security", "wrong-key"),
+            _ => {}
+        }
+        let mut request = request.body(Body::empty()).unwrap();
+
+        let extensions = request.extensions_mut();
+        extensions.insert(CurrentStore(store.clone()));
+        let rank = match principal {
+            Principal::Vip => Rank::Vip,
+            Principal::Administrator | Principal::AdministratorWithoutTwoFactor => Rank::Administrator,
+            _ => Rank::Customer,
+        };
+        match principal {
+            Principal::Customer | Principal::Vip | Principal::Administrator | Principal::AdministratorWithoutTwoFactor => {
+                extensions.insert(auth_user(store.clone(), user(&store, rank, Vec::new())));
+            }
+            Principal::CustomRole => {
+                let user = user(&store, rank, vec!["stock-manager".to_string()]);
+                extensions.insert(auth_user(store.clone(), user.clone()));
+                let permissions = CUSTOM_ROLE_PERMISSIONS.into_iter().collect();
+                extensions.insert(Caller { store, user, permissions, via_api_key: false });
+            }
+            Principal::ApiKey => {
+                let user = user(&store, Rank::Administrator, Vec::new());
+                let permissions = API_KEY_PERMISSIONS.into_iter().collect();
+                extensions.insert(Caller { store, user, permissions, via_api_key: true });
+            }
+            _ => {}
+        }
+        request
+    }
+
+    // 핸들러는 DB 를 쓰므로 정책을 통과한 요청은 핸들러 직전에서 멈춥니다.
+    async fn stop_before_handler(_request: Request, _next: Next) -> &'static str {
+        "passed"
+    }
+
+    async fn router() -> Router {
+        let mongo_repo = Arc::new(MongoRepository::connect().await.unwrap());
+        let mailer: Arc<dyn Mailer> = Arc::new(InMemoryMailer::new());
+        build_router(routes().route_layer(middleware::from_fn(stop_before_handler)), mongo_repo, mailer)
+    }
+
+    async fn outcome(router: &Router, request: Request) -> Outcome {
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        if status == StatusCode::OK && &body[..] == b"passed" {
+            return Outcome::Passed;
+        }
+
+        let error: AppErrorResponse = serde_json::from_slice(&body)
+            .unwrap_or_else(|_| panic!("예상하지 못한 응답입니다: {} {}", status, String::from_utf8_lossy(&body)));
+        let code = ROUTE_POLICY_CODES.iter().find(|code| **code == error.code).copied().unwrap_or("UNKNOWN");
+        Outcome::Rejected(status, code)
+    }
+
+    const ROUTE_POLICY_CODES: [&str; 8] = [
+        "SECURITY_KEY_REQUIRED",
+        "INVALID_SECURITY_KEY",
+        "AUTHORIZATION_REQUIRED",
+        "API_KEY_NOT_ACCEPTED",
+        "API_KEY_NOT_ALLOWED",
+        "PERMISSION_DENIED",
+        "ADMIN_TWO_FACTOR_REQUIRED",
+        "ROUTE_POLICY_MISSING",
+    ];
+
+    #[tokio::test]
+    async fn every_route_policy_admits_only_its_principals() {
+        init_config();
+        let router = router().await;
+
+        for (method, path, access) in ROUTE_POLICIES {
+            for principal in PRINCIPALS {
+                let actual = outcome(&router, request(method, path, principal)).await;
+                assert_eq!(actual, expected(*access, principal), "{} {} ({:?})", method, path, principal);
+            }
+        }
+    }
+
+    #[test]
+    fn admin_and_store_routes_are_never_opened_up() {
+        for (method, path, access) in ROUTE_POLICIES {
+            if path.starts_with("/api/admin/") {
+                assert!(matches!(access, Access::Permission(_)), "{} {}: {:?}", method, path, access);
+            }
+            if path.starts_with("/api/store/") {
+                assert_eq!(*access, Access::SecurityKey, "{} {}", method, path);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn unknown_routes_are_not_found_before_the_policy_check() {
+        init_config();
+        let router = router().await;
+        let response = router.oneshot(request("GET", "/api/unknown", Principal::Anonymous)).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    // 정책 표에 없는 라우트는 policy_route 가 등록 단계에서 막으므로 routes() 가 만들어지면 등록된 라우트는 모두 정책이 있습니다.
+    // 반대로 정책만 남고 라우트가 사라졌다면 라우터가 404 나 405 로 답합니다.
+    #[tokio::test]
+    async fn every_route_policy_matches_a_registered_route() {
+        let router = routes().route_layer(middleware::from_fn(stop_before_handler));
+
+        for (method, path, _) in ROUTE_POLICIES {
+            let request = request(method, path, Principal::Anonymous);
+            let response = router.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK, "등록되지 않은 라우트의 정책: {} {}", method, path);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "접근 권한이 등록되지 않은 라우트입니다")]
+    fn routes_without_a_policy_are_refused_at_registration() {
+        let _ = Router::new().policy_route(Method::GET, "/api/unregistered", || async {});
+    }
+
+    // 요청 확장을 비워 둔 채 실제 핸들러까지 연결된 라우터로 보내 AuthUser, Caller 추출기가 직접 토큰과 스토어를 확인하게 합니다.
+    fn bare_request(method: &str, path: &str, headers: &[(&str, &str)]) -> Request {
+        let uri: Vec<&str> = path.split('/').map(|segment| if segment.starts_with(':') { "x" } else { segment }).collect();
+        let mut request = Request::builder().method(method).uri(uri.join("/"));
+        for (name, value) in headers {
+            request = request.header(*name, *value);
+        }
+        request.body(Body::empty()).unwrap()
+    }
+
+    async fn rejection(router: &Router, request: Request) -> (StatusCode, String) {
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let error: AppErrorResponse = serde_json::from_slice(&body)
+            .unwrap_or_else(|_| panic!("예상하지 못한 응답입니다: {} {}", status, String::from_utf8_lossy(&body)));
+        (status, error.code)
+    }
+
+    #[tokio::test]
+    async fn real_extractors_reject_missing_and_invalid_tokens() {
+        init_config();
+        let mongo_repo = Arc::new(MongoRepository::connect().await.unwrap());
+        let mailer: Arc<dyn Mailer> = Arc::new(InMemoryMailer::new());
+        let router = build_router(routes(), mongo_repo, mailer);
+
+        for (method, path, access) in ROUTE_POLICIES {
+            if matches!(access, Access::Public | Access::SecurityKey) {
+                continue;
+            }
+
+            let missing = rejection(&router, bare_request(method, path, &[("X-Store-Name", "acme")])).await;
+            assert_eq!(missing, (StatusCode::UNAUTHORIZED, "AUTHORIZATION_REQUIRED".to_string()), "{} {}", method, path);
+
+            let headers = [("X-Store-Name", "acme"), ("Authorization", "Bearer not-a-token")];
+            let invalid = rejection(&router, bare_request(method, path, &headers)).await;
+            assert_eq!(invalid, (StatusCode::UNAUTHORIZED, "INVALID_TOKEN".to_string()), "{} {}", method, path);
+        }
+    }
+
+    // 서명이 맞는 토큰이면 추출기가 스토어와 유저를 조회하러 가므로, 닿지 않는 테스트 DB 에서는 503 이 돌아와야 합니다.
+    #[tokio::test]
+    async fn real_extractors_look_up_the_store_for_a_valid_token() {
+        init_config();
+        let mongo_repo = Arc::new(MongoRepository::connect().await.unwrap());
+        let mailer: Arc<dyn Mailer> = Arc::new(InMemoryMailer::new());
+        let router = build_router(routes(), mongo_repo, mailer);
+        let token = generate_jwt(ObjectId::new(), ObjectId::new(), ObjectId::new(), &CONFIG.jwt_secret);
+        let bearer = format!("Bearer {}", token);
+
+        for (method, path) in [("GET", "/api/users/me"), ("GET", "/api/admin/users")] {
+            let without_store = rejection(&router, bare_request(method, path, &[("Authorization", &bearer)])).await;
+            assert_eq!(without_store, (StatusCode::BAD_REQUEST, "STORE_NAME_REQUIRED".to_string()), "{} {}", method, path);
+
+            let headers = [("X-Store-Name", "acme"), ("Authorization", bearer.as_str())];
+            let unreachable = rejection(&router, bare_request(method, path, &headers)).await;
+            assert_eq!(unreachable, (StatusCode::SERVICE_UNAVAILABLE, "DATABASE_UNAVAILABLE".to_string()), "{} {}", method, path);
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use chrono::{Duration, Utc};
+use mongodb::options::ReturnDocument;
+use mongodb::bson::{doc, DateTime};
+use mongodb::bson::oid::ObjectId;
+use mongodb::error::Result;
+use crate::auth::model::{ApiKey, LoginAttempt, PasswordResetToken, RefreshToken, RevokedToken, Session};
+use crate::config::CONFIG;
+use crate::database::MongoRepository;
+
+const SESSION_SEEN_INTERVAL_SECS: i64 = 60;
+
+impl MongoRepository {
+    pub async fn create_refresh_token(&self, new_token: RefreshToken) -> Result<ObjectId> {
+        let token = self.refresh_token_collection.insert_one(new_token).await?;
+        Ok(token.inserted_id.as_object_id().unwrap())
+    }
+
+    pub async fn use_refresh_token(&self, store_id: &ObjectId, token_hash: &str) -> Result<Option<RefreshToken>> {
+        let filter = doc! {
+            "store_id": store_id,
+            "token_hash": token_hash,
+            "used": false,
+            "revoked": false,
+            "expires_at": { "$gt": DateTime::from_chrono(Utc::now()) },
+        };
+        let update = doc! { "$set": { "used": true } };
+        self.refresh_token_collection.find_one_and_update(filter, update).await
+    }
+
+    pub async fn find_refresh_token(&self, store_id: &ObjectId, token_hash: &str) -> Result<Option<RefreshToken>> {
+        let filter = doc! { "store_id": store_id, "token_hash": token_hash };
+        self.refresh_token_collection.find_one(filter).await
+    }
+
+    pub async fn revoke_refresh_token_family(&self, family_id: &ObjectId) -> Result<()> {
+        let filter = doc! { "family_id": family_id };
+        let update = doc! { "$set": { "revoked": true } };
+        self.refresh_token_collection.update_many(filter, update).await?;
+        Ok(())
+    }
+
+    pub async fn is_refresh_token_family_revoked(&self, family_id: &ObjectId) -> Result<bool> {
+        let filter = doc! { "family_id": family_id, "revoked": true };
+        Ok(self.refresh_token_collection.find_one(filter).await?.is_some())
+    }
+
+    pub async fn revoke_user_refresh_tokens(&self, store_id: &ObjectId, user_id: &ObjectId) -> Result<()> {
+        let filter = doc! { "store_id": store_id, "user_id": user_id };
+        let update = doc! { "$set": { "revoked": true } };
+        self.refresh_token_collection.update_many(filter, update).await?;
+        Ok(())
+    }
+
+    pub async fn revoke_access_token(&self, revoked_token: RevokedToken) -> Result<()> {
+        self.revoked_token_collection.insert_one(revoked_token).await?;
+        Ok(())
+    }
+
+    pub async fn is_access_token_revoked(
+        &self,
+        store_id: &ObjectId,
+        user_id: &ObjectId,
+        jti: &str,
+        sid: &str,
+        issued_at: DateTime,
+    ) -> Result<bool> {
+        let filter = doc! {
+            "store_id": store_id,
+            "user_id": user_id,
+            "$or": [
+                { "jti": jti },
+                { "sid": sid },
+                { "revoked_before": { "$gt": issued_at } },
+            ],
+        };
+        Ok(self.revoked_token_collection.find_one(filter).await?.is_some())
+    }
+
+    pub async fn replace_password_reset_token(&self, new_token: PasswordResetToken) -> Result<()> {
+        let filter = doc! { "store_id": new_token.store_id, "user_id": new_token.user_id };
+        self.password_reset_collection.delete_many(filter).await?;
+        self.password_reset_collection.insert_one(new_token).await?;
+        Ok(())
+    }
+
+    pub async fn take_password_reset_token(&self, store_id: &ObjectId, token_hash: &str) -> Result<Option<PasswordResetToken>> {
+        let filter = doc! {
+            "store_id": store_id,
+            "token_hash": token_hash,
+            "expires_at": { "$gt": DateTime::from_chrono(Utc::now()) },
+        };
+        self.password_reset_collection.find_one_and_delete(filter).await
+    }
+
+    pub async fn delete_user_password_reset_tokens(&self, store_id: &ObjectId, user_id: &ObjectId) -> Result<()> {
+        let filter = doc! { "store_id": store_id, "user_id": user_id };
+        self.password_reset_collection.delete_many(filter).await?;
+        Ok(())
+    }
+
+    pub async fn reserve_login_attempt(&self, store_id: &ObjectId, key: &str) -> Result<Option<LoginAttempt>> {
+        let filter = doc! { "store_id": store_id, "key": key };
+        let update = doc! {
+            "$inc": { "in_flight": 1 },
+            "$setOnInsert": { "failures": 0 },
+            "$max": {
+                "expires_at": DateTime::from_chrono(Utc::now() + Duration::seconds(CONFIG.login_failure_window_secs)),
+            },
+        };
+
+        self.login_attempt_collection
+            .find_one_and_update(filter, update)
+            .upsert(true)
+            .return_document(ReturnDocument::After)
+            .await
+    }
+
+    pub async fn release_login_attempt(&self, store_id: &ObjectId, key: &str) -> Result<()> {
+        let filter = doc! { "store_id": store_id, "key": key, "in_flight": { "$gt": 0 } };
+        let update = doc! { "$inc": { "in_flight": -1 } };
+        self.login_attempt_collection.update_one(filter, update).await?;
+        Ok(())
+    }
+
+    // 실패 횟수와 잠금을 먼저 반영한 뒤 예약을 풀어, 그 사이 들어온 시도가 잠금 전 상태를 보지 못하게 합니다.
+    pub async fn record_login_failure(&self, store_id: &ObjectId, key: &str, max_failures: i64) -> Result<()> {
+        let now = Utc::now();
+        let filter = doc! { "store_id": store_id, "key": key };
+        let update = doc! {
+            "$inc": { "failures": 1 },
+            "$max": { "expires_at": DateTime::from_chrono(now + Duration::seconds(CONFIG.login_failure_window_secs)) },
+        };
+
+        let attempt = self
+            .login_attempt_collection
+            .find_one_and_update(filter.clone(), update)
+            .upsert(true)
+            .return_document(ReturnDocument::After)
+            .await?;
+
+        if let Some(lock) = attempt.and_then(|attempt| LoginAttempt::lock_duration(attempt.failures, max_failures)) {
+            let locked_until = now + lock;
+            let update = doc! {
+                "$set": {
+                    "locked_until": DateTime::from_chrono(locked_until),
+                    "expires_at": DateTime::from_chrono(locked_until + Duration::seconds(CONFIG.login_failure_window_secs)),
+                }
+            };
+            self.login_attempt_collection.update_one(filter, update).await?;
+        }
+
+        self.release_login_attempt(store_id, key).await
+    }
+
+    pub async fn clear_login_failures(&self, store_id: &ObjectId, key: &str) -> Result<bool> {
+        let filter = doc! { "store_id": store_id, "key": key };
+        let result = self.login_attempt_collection.delete_one(filter).await?;
+        Ok(result.deleted_count > 0)
+    }
+
+    pub async fn create_api_key(&self, new_key: ApiKey) -> Result<ObjectId> {
+        let api_key = self.api_key_collection.insert_one(new_key).await?;
+        Ok(api_key.inserted_id.as_object_id().unwrap())
+    }
+
+    pub async fn find_api_keys(&self, store_id: &ObjectId) -> Result<Vec<ApiKey>> {
+        let filter = doc! { "store_id": store_id };
+        let mut cursor = self.api_key_collection.find(filter).sort(doc! { "_id": -1 }).await?;
+
+        let mut api_keys = vec![];
+        while cursor.advance().await? {
+            api_keys.push(cursor.deserialize_current()?);
+        }
+        Ok(api_keys)
+    }
+
+    pub async fn use_api_key(&self, store_id: &ObjectId, key_hash: &str) -> Result<Option<ApiKey>> {
+        let now = DateTime::from_chrono(Utc::now());
+        let filter = doc! {
+            "store_id": store_id,
+            "key_hash": key_hash,
+            "$or": [
+                { "expires_at": null },
+                { "expires_at": { "$gt": now } },
+            ],
+        };
+        let update = doc! { "$set": { "last_used_at": now } };
+        self.api_key_collection
+            .find_one_and_update(filter, update)
+            .return_document(ReturnDocument::After)
+            .await
+    }
+
+    pub async fn delete_api_key(&self, store_id: &ObjectId, id: &ObjectId) -> Result<bool> {
+        let filter = doc! { "_id": id, "store_id": store_id };
+        let result = self.api_key_collection.delete_one(filter).await?;
+        Ok(result.deleted_count > 0)
+    }
+
+    pub async fn delete_user_api_keys(&self, store_id: &ObjectId, user_id: &ObjectId) -> Result<()> {
+        let filter = doc! { "store_id": store_id, "created_by": user_id };
+        self.api_key_collection.delete_many(filter).await?;
+        Ok(())
+    }
+
+    pub async fn create_session(&self, session: Session) -> Result<()> {
+        self.session_collection.insert_one(session).await?;
+        Ok(())
+    }
+
+    // 끊긴 세션이 갱신으로 되살아나지 않도록 새로 만들지 않고, 세션이 남아 있는지만 알려 줍니다.
+    pub async fn touch_session(&self, store_id: &ObjectId, user_id: &ObjectId, session_id: &ObjectId) -> Result<bool> {
+        let now = Utc::now();
+        let filter = doc! { "_id": session_id, "store_id": store_id, "user_id": user_id };
+        let update = doc! {
+            "$set": {
+                "last_seen_at": DateTime::from_chrono(now),
+                "expires_at": DateTime::from_chrono(now + Duration::seconds(CONFIG.refresh_token_ttl_secs)),
+            },
+        };
+        let result = self.session_collection.update_one(filter, update).await?;
+        Ok(result.matched_count > 0)
+    }
+
+    // 요청마다 쓰기가 일어나지 않도록 마지막 접속 시각은 일정 간격이 지났을 때만 갱신합니다.
+    pub async fn mark_session_seen(&self, session_id: &ObjectId) -> Result<()> {
+        let now = Utc::now();
+        let filter = doc! {
+            "_id": session_id,
+            "last_seen_at": { "$lt": DateTime::from_chrono(now - Duration::seconds(SESSION_SEEN_INTERVAL_SECS)) },
+        };
+        let update = doc! { "$set": { "last_seen_at": DateTime::from_chrono(now) } };
+        self.session_collection.update_one(filter, update).await?;
+        Ok(())
+    }
+
+    pub async fn find_user_sessions(&self, store_id: &ObjectId, user_id: &ObjectId) -> Result<Vec<Session>> {
+        let filter = doc! {
+            "store_id": store_id,
+            "user_id": user_id,
+            "expires_at": { "$gt": DateTime::from_chrono(Utc::now()) },
+        };
+        let mut cursor = self.session_collection.find(filter).sort(doc! { "last_seen_at": -1 }).await?;
+
+        let mut sessions = vec![];
+        while cursor.advance().await? {
+            sessions.push(cursor.deserialize_current()?);
+        }
+        Ok(sessions)
+    }
+
+    pub async fn delete_session(&self, store_id: &ObjectId, user_id: &ObjectId, session_id: &ObjectId) -> Result<bool> {
+        let filter = doc! { "_id": session_id, "store_id": store_id, "user_id": user_id };
+        let result = self.session_collection.delete_one(filter).await?;
+        Ok(result.deleted_count > 0)
+    }
+
+    pub async fn delete_user_sessions(&self, store_id: &ObjectId, user_id: &ObjectId) -> Result<()> {
+        let filter = doc! { "store_id": store_id, "user_id": user_id };
+        self.session_collection.delete_many(filter).await?;
+        Ok(())
+    }
+}
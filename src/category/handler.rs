@@ -1,90 +1,68 @@
 use std::sync::Arc;
 use axum::{Extension, Json, Router};
 use axum::extract::Path;
-use axum::http::{HeaderMap, StatusCode};
+use axum::http::{Method, StatusCode};
 use axum::response::IntoResponse;
-use axum::routing::{delete, post};
-use crate::category::model::{Category, CategoryResponse, CreateCategoryRequest};
+use crate::auth::policy::PolicyRouter;
+use crate::auth::extractor::{Authorized, CurrentStore};
+use crate::category::model::{Category, CategoryListResponse, CategoryResponse, CreateCategoryRequest};
+use crate::common::error::AppError;
+use crate::common::i18n::Message;
 use crate::common::response::ErrorResponse;
 use crate::common::types::Status;
 use crate::database::MongoRepository;
-use crate::store::helper::get_store_from_headers;
 
 pub fn category_routes() -> Router {
     Router::new()
-        .route("/api/category", post(create_category))
-        .route("/api/category/:category_name", delete(delete_category))
+        .policy_route(Method::POST, "/api/category", create_category)
+        .policy_route(Method::GET, "/api/category", list_categories)
+        .policy_route(Method::DELETE, "/api/category/:category_name", delete_category)
 }
 
 pub async fn create_category(
-    headers: HeaderMap,
+    Authorized { store, .. }: Authorized,
     Extension(mongo_repo): Extension<Arc<MongoRepository>>,
     Json(body): Json<CreateCategoryRequest>,
-) -> Result<impl IntoResponse, StatusCode> {
-    let store = match get_store_from_headers(&headers, &mongo_repo).await {
-        Ok(store) => store,
-        Err(err) => return Ok(err.into_response()),
-    };
-
-    if let Some(_) = mongo_repo.find_category_by_name(store.object_id.unwrap(), &body.name).await {
-        let error_response = ErrorResponse {
-            status: Status::Failure,
-            message: "이미 존재하는 카테고리 이름입니다.".to_string(),
-        };
-        return Ok((StatusCode::CONFLICT, Json(error_response)).into_response());
+) -> Result<impl IntoResponse, AppError> {
+    if mongo_repo.find_category_by_name(store.object_id.unwrap(), &body.name).await?.is_some() {
+        return Err(AppError::Conflict(Message::new("CATEGORY_NAME_TAKEN")));
     }
 
     let category = Category::new(store.object_id.unwrap(), body.name.clone(), body.description.clone());
+    mongo_repo.create_category(category.clone()).await?;
 
-    match mongo_repo.create_category(category.clone()).await {
-        Ok(_) => {
-            let response = CategoryResponse {
-                status: Status::Success,
-                category,
-            };
-            Ok((StatusCode::CREATED, Json(response)).into_response())
-        }
-        Err(_) => {
-            let error_response = ErrorResponse {
-                status: Status::Error,
-                message: "카테고리 생성에 실패하였습니다.".to_string(),
-            };
-            Ok((StatusCode::INTERNAL_SERVER_ERROR, Json(error_response)).into_response())
-        }
-    }
+    let response = CategoryResponse {
+        status: Status::Success,
+        category: (&category).into(),
+    };
+    Ok((StatusCode::CREATED, Json(response)))
 }
 
 pub async fn delete_category(
-    headers: HeaderMap,
+    Authorized { store, .. }: Authorized,
     Path(category_name): Path<String>,
     Extension(mongo_repo): Extension<Arc<MongoRepository>>,
-) -> Result<impl IntoResponse, StatusCode> {
-    let store = match get_store_from_headers(&headers, &mongo_repo).await {
-        Ok(store) => store,
-        Err(err) => return Ok(err.into_response()),
+) -> Result<impl IntoResponse, AppError> {
+    if !mongo_repo.delete_category(store.object_id.unwrap(), &category_name).await? {
+        return Err(AppError::NotFound(Message::new("CATEGORY_NOT_FOUND").arg(category_name)));
+    }
+
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("CATEGORY_DELETED").arg(category_name).localize(),
     };
+    Ok((StatusCode::OK, Json(response)))
+}
 
-    match mongo_repo.delete_category(store.object_id.unwrap(), &category_name).await {
-        Ok(true) => {
-            let response = ErrorResponse {
-                status: Status::Success,
-                message: format!("카테고리 '{}' 삭제 성공.", category_name),
-            };
-            Ok((StatusCode::OK, Json(response)).into_response())
-        }
-        Ok(false) => {
-            let error_response = ErrorResponse {
-                status: Status::Failure,
-                message: format!("카테고리 '{}'를 찾을 수 없습니다.", category_name),
-            };
-            Ok((StatusCode::NOT_FOUND, Json(error_response)).into_response())
-        }
-        Err(_) => {
-            let error_response = ErrorResponse {
-                status: Status::Error,
-                message: "카테고리 삭제에 실패하였습니다.".to_string(),
-            };
-            Ok((StatusCode::INTERNAL_SERVER_ERROR, Json(error_response)).into_response())
-        }
-    }
-}
\ No newline at end of file
+pub async fn list_categories(
+    CurrentStore(store): CurrentStore,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    let categories = mongo_repo.find_category_summaries(store.object_id.unwrap()).await?;
+
+    let response = CategoryListResponse {
+        status: Status::Success,
+        categories: categories.iter().map(Into::into).collect(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
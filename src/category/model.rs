@@ -1,6 +1,6 @@
 use bson::oid::ObjectId;
 use serde::{Deserialize, Serialize};
-use crate::category::product::model::Product;
+use crate::category::product::model::{Product, PublicProduct};
 use crate::common::types::Status;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -36,8 +36,33 @@ pub struct CreateCategoryRequest {
     pub description: String,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PublicCategory {
+    pub id: Option<String>,
+    pub name: String,
+    pub description: String,
+    pub products: Vec<PublicProduct>,
+}
+
+impl From<&Category> for PublicCategory {
+    fn from(category: &Category) -> Self {
+        PublicCategory {
+            id: category.object_id.map(|id| id.to_hex()),
+            name: category.name.clone(),
+            description: category.description.clone(),
+            products: category.products.iter().map(PublicProduct::from).collect(),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct CategoryResponse {
     pub status: Status,
-    pub category: Category,
+    pub category: PublicCategory,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CategoryListResponse {
+    pub status: Status,
+    pub categories: Vec<PublicCategory>,
 }
\ No newline at end of file
@@ -1,8 +1,6 @@
 use std::sync::Arc;
-use axum::http::StatusCode;
-use axum::{Json};
-use crate::common::response::ErrorResponse;
-use crate::common::types::Status;
+use crate::common::error::AppError;
+use crate::common::i18n::Message;
 use crate::database::MongoRepository;
 use crate::category::model::Category;
 
@@ -10,32 +8,32 @@ pub async fn get_category_from_store(
     store_id: &Option<bson::oid::ObjectId>,
     category_name: &str,
     mongo_repo: &Arc<MongoRepository>,
-) -> Result<Category, (StatusCode, Json<ErrorResponse>)> {
-    match mongo_repo.find_category_by_name(store_id.unwrap(), category_name).await {
-        Some(category) => Ok(category),
-        None => {
-            let error_response = ErrorResponse {
-                status: Status::Failure,
-                message: format!("카테고리 '{}'를 찾을 수 없습니다.", category_name),
-            };
-            Err((StatusCode::NOT_FOUND, Json(error_response)))
-        }
-    }
+) -> Result<Category, AppError> {
+    mongo_repo
+        .find_category_by_name(store_id.unwrap(), category_name)
+        .await?
+        .ok_or_else(|| AppError::NotFound(Message::new("CATEGORY_NOT_FOUND").arg(category_name)))
+}
+
+// 재고 코드가 필요 없는 경로에서 씁니다. 제품의 재고 배열은 비어 있고 재고 수는 stock_count() 로 읽습니다.
+pub async fn get_category_summary_from_store(
+    store_id: &Option<bson::oid::ObjectId>,
+    category_name: &str,
+    mongo_repo: &Arc<MongoRepository>,
+) -> Result<Category, AppError> {
+    mongo_repo
+        .find_category_summary_by_name(store_id.unwrap(), category_name)
+        .await?
+        .ok_or_else(|| AppError::NotFound(Message::new("CATEGORY_NOT_FOUND").arg(category_name)))
 }
 
 pub fn find_product_in_category<'a>(
     category: &'a mut Category,
     product_name: &str,
-) -> Result<&'a mut crate::category::product::model::Product, (StatusCode, Json<ErrorResponse>)> {
-    let product = category.products.iter_mut().find(|p| p.name == product_name);
-    match product {
-        Some(p) => Ok(p),
-        None => {
-            let error_response = ErrorResponse {
-                status: Status::Failure,
-                message: format!("제품 '{}'를 찾을 수 없습니다.", product_name),
-            };
-            Err((StatusCode::NOT_FOUND, Json(error_response)))
-        }
-    }
-}
\ No newline at end of file
+) -> Result<&'a mut crate::category::product::model::Product, AppError> {
+    category
+        .products
+        .iter_mut()
+        .find(|p| p.name == product_name)
+        .ok_or_else(|| AppError::NotFound(Message::new("PRODUCT_NOT_FOUND").arg(product_name)))
+}
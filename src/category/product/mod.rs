@@ -2,4 +2,5 @@ pub mod model;
 pub mod repository;
 pub mod handler;
 pub mod helper;
-pub mod stock;
\ No newline at end of file
+pub mod stock;
+pub mod restock;
\ No newline at end of file
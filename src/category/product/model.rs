@@ -11,6 +11,13 @@ pub struct Product {
     pub price: f64,
     pub discount_rate: Option<f64>,
     pub stock: Vec<String>,
+    #[serde(default)]
+    pub low_stock_threshold: Option<u32>,
+    #[serde(default)]
+    pub low_stock_alerted: bool,
+    // 재고 배열 대신 크기만 받아 온 조회에서 채워집니다. DB 에는 쓰지 않습니다.
+    #[serde(default, skip_serializing)]
+    pub counted_stock: Option<usize>,
 }
 
 #[allow(dead_code)]
@@ -23,9 +30,16 @@ impl Product {
             price,
             discount_rate: None,
             stock: vec![],
+            low_stock_threshold: None,
+            low_stock_alerted: false,
+            counted_stock: None,
         }
     }
 
+    pub fn stock_count(&self) -> usize {
+        self.counted_stock.unwrap_or(self.stock.len())
+    }
+
     pub fn final_price(&self) -> f64 {
         if let Some(rate) = self.discount_rate {
             self.price * (1.0 - rate)
@@ -35,10 +49,43 @@ impl Product {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PublicProduct {
+    pub id: Option<String>,
+    pub name: String,
+    pub description: String,
+    pub price: f64,
+    pub discount_rate: Option<f64>,
+    pub final_price: f64,
+    pub stock_count: usize,
+    pub in_stock: bool,
+}
+
+impl From<&Product> for PublicProduct {
+    fn from(product: &Product) -> Self {
+        PublicProduct {
+            id: product.object_id.map(|id| id.to_hex()),
+            name: product.name.clone(),
+            description: product.description.clone(),
+            price: product.price,
+            discount_rate: product.discount_rate,
+            final_price: product.final_price(),
+            stock_count: product.stock_count(),
+            in_stock: product.stock_count() > 0,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ProductResponse {
     pub status: Status,
-    pub product: Product,
+    pub product: PublicProduct,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProductListResponse {
+    pub status: Status,
+    pub products: Vec<PublicProduct>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
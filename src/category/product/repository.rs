@@ -1,9 +1,19 @@
-use bson::{doc, to_bson};
+use std::collections::HashSet;
+use bson::{bson, doc, to_bson, Bson, Document};
 use bson::oid::ObjectId;
+use mongodb::error::ErrorKind;
+use mongodb::options::ReturnDocument;
 use crate::category::model::Category;
 use crate::category::product::model::Product;
+use crate::category::product::stock::model::StockChange;
 use crate::database::MongoRepository;
 
+const TAKE_STOCK_MAX_ATTEMPTS: usize = 5;
+// 카테고리 문서 하나에 모든 제품의 재고가 들어가므로 제품마다 상한을 두어 문서 크기 제한에 닿지 않게 합니다.
+// 암호화된 코드는 최대 400바이트 정도라 상한까지 채워도 제품 하나가 4MB 를 넘지 않습니다.
+pub const MAX_PRODUCT_STOCK: usize = 10_000;
+const WRITE_CONFLICT_CODE: i32 = 112;
+
 #[allow(dead_code)]
 impl MongoRepository {
     pub async fn add_product_to_category(
@@ -36,31 +46,357 @@ impl MongoRepository {
         store_id: &ObjectId,
         category_name: &str,
         product_id: ObjectId,
-    ) -> Option<Product> {
+    ) -> mongodb::error::Result<Option<Product>> {
         let filter = doc! {
             "store_id": store_id,
             "name": category_name,
             "products._id": product_id
         };
-        let category: Option<Category> = self.category_collection.find_one(filter).await.unwrap_or(None);
+        let category: Option<Category> = self.category_collection.find_one(filter).await?;
 
-        category.and_then(|c| c.products.into_iter().find(|p| p.object_id == Some(product_id)))
+        Ok(category.and_then(|c| c.products.into_iter().find(|p| p.object_id == Some(product_id))))
     }
 
-    pub async fn update_product_stock(
+    pub async fn take_product_stock(
         &self,
         store_id: &ObjectId,
         category_name: &str,
         product_name: &str,
-        stock: &Vec<String>,
-    ) -> mongodb::error::Result<()> {
+        quantity: usize,
+    ) -> mongodb::error::Result<Option<Vec<String>>> {
+        if quantity == 0 {
+            return Ok(Some(Vec::new()));
+        }
+
+        // 재고가 quantity 개 이상인 경우에만 매칭되고, 앞에서부터 quantity 개를 한 번에 잘라낸다.
         let filter = doc! {
             "store_id": store_id,
             "name": category_name,
-            "products.name": product_name,
+            "products": {
+                "$elemMatch": {
+                    "name": product_name,
+                    format!("stock.{}", quantity - 1): { "$exists": true },
+                }
+            },
         };
-        let update = doc! { "$set": { "products.$.stock": stock } };
-        self.category_collection.update_one(filter, update).await?;
-        Ok(())
+        let update = vec![doc! {
+            "$set": {
+                "products": {
+                    "$map": {
+                        "input": "$products",
+                        "as": "product",
+                        "in": {
+                            "$cond": [
+                                { "$eq": ["$$product.name", product_name] },
+                                {
+                                    "$mergeObjects": [
+                                        "$$product",
+                                        { "stock": { "$slice": ["$$product.stock", quantity as i64, i32::MAX] } },
+                                    ]
+                                },
+                                "$$product",
+                            ]
+                        },
+                    }
+                }
+            }
+        }];
+
+        let mut attempts = 0;
+        let category = loop {
+            attempts += 1;
+            match self
+                .category_collection
+                .find_one_and_update(filter.clone(), update.clone())
+                .projection(stock_projection(bson!({ "$slice": ["$$product.stock", quantity as i64] })))
+                .return_document(ReturnDocument::Before)
+                .await
+            {
+                Ok(category) => break category,
+                Err(err) if attempts < TAKE_STOCK_MAX_ATTEMPTS && is_write_conflict(&err) => continue,
+                Err(err) => return Err(err),
+            }
+        };
+
+        Ok(category
+            .and_then(|c| c.products.into_iter().find(|p| p.name == product_name))
+            .map(|p| p.stock.into_iter().take(quantity).collect()))
+    }
+
+    // capacity 가 있으면 넣은 뒤의 재고가 그 수를 넘지 않을 때만 넣고, 넘으면 제품이 없을 때처럼 None 을 돌려줍니다.
+    // 꺼냈던 코드를 되돌리는 경로는 원래 있던 재고이므로 capacity 없이 넣습니다.
+    pub async fn push_product_stock(
+        &self,
+        store_id: &ObjectId,
+        category_name: &str,
+        product_name: &str,
+        codes: &[String],
+        capacity: Option<usize>,
+    ) -> mongodb::error::Result<Option<StockChange>> {
+        let Some(filter) = capped_product_filter(store_id, category_name, product_name, codes.len(), capacity) else {
+            return Ok(None);
+        };
+        let update = doc! { "$push": { "products.$.stock": { "$each": codes } } };
+        let previous = self
+            .find_previous_product_stock(filter, update, stock_projection(bson!([])), product_name)
+            .await?;
+
+        Ok(previous.map(|product| StockChange {
+            previous: product.stock_count(),
+            current: product.stock_count() + codes.len(),
+        }))
+    }
+
+    pub async fn push_unique_product_stock(
+        &self,
+        store_id: &ObjectId,
+        category_name: &str,
+        product_name: &str,
+        codes: &[String],
+        capacity: Option<usize>,
+    ) -> mongodb::error::Result<Option<StockChange>> {
+        let Some(filter) = capped_product_filter(store_id, category_name, product_name, codes.len(), capacity) else {
+            return Ok(None);
+        };
+        let update = doc! { "$addToSet": { "products.$.stock": { "$each": codes } } };
+        // 이미 있던 코드를 세는 데 필요한 만큼, 넣으려는 코드와 겹치는 재고만 받아옵니다.
+        let projection = stock_projection(bson!({ "$setIntersection": ["$$product.stock", codes] }));
+        let previous = self.find_previous_product_stock(filter, update, projection, product_name).await?;
+
+        Ok(previous.map(|product| {
+            let existing: HashSet<&String> = product.stock.iter().collect();
+            let added = codes.iter().filter(|code| !existing.contains(code)).collect::<HashSet<_>>().len();
+            StockChange {
+                previous: product.stock_count(),
+                current: product.stock_count() + added,
+            }
+        }))
+    }
+
+    pub async fn count_product_stock(
+        &self,
+        store_id: &ObjectId,
+        category_name: &str,
+        product_name: &str,
+    ) -> mongodb::error::Result<Option<usize>> {
+        let filter = product_filter(store_id, category_name, product_name);
+        let category = self
+            .category_collection
+            .find_one(filter)
+            .projection(stock_projection(bson!([])))
+            .await?;
+
+        Ok(category
+            .and_then(|c| c.products.into_iter().find(|p| p.name == product_name))
+            .map(|p| p.stock_count()))
+    }
+
+    // 목록과 조회 응답에는 재고 수만 필요하므로 재고 배열 대신 그 크기만 받아옵니다.
+    pub async fn find_category_summary_by_name(
+        &self,
+        store_id: ObjectId,
+        name: &str,
+    ) -> mongodb::error::Result<Option<Category>> {
+        let filter = doc! { "store_id": store_id, "name": name };
+        self.category_collection
+            .find_one(filter)
+            .projection(stock_projection(bson!([])))
+            .await
+    }
+
+    pub async fn find_category_summaries(&self, store_id: ObjectId) -> mongodb::error::Result<Vec<Category>> {
+        let filter = doc! { "store_id": store_id };
+        let mut cursor = self
+            .category_collection
+            .find(filter)
+            .projection(stock_projection(bson!([])))
+            .sort(doc! { "name": 1 })
+            .await?;
+
+        let mut categories = vec![];
+        while cursor.advance().await? {
+            categories.push(cursor.deserialize_current()?);
+        }
+        Ok(categories)
+    }
+
+    pub async fn find_store_stock_codes(&self, store_id: &ObjectId) -> mongodb::error::Result<HashSet<String>> {
+        let mut cursor = self.category_collection.find(doc! { "store_id": store_id }).await?;
+
+        let mut codes = HashSet::new();
+        while cursor.advance().await? {
+            let category = cursor.deserialize_current()?;
+            codes.extend(category.products.into_iter().flat_map(|p| p.stock));
+        }
+        Ok(codes)
+    }
+
+    pub async fn pull_product_stock(
+        &self,
+        store_id: &ObjectId,
+        category_name: &str,
+        product_name: &str,
+        codes: &[String],
+    ) -> mongodb::error::Result<Option<usize>> {
+        let filter = product_filter(store_id, category_name, product_name);
+        let update = doc! { "$pull": { "products.$.stock": { "$in": codes } } };
+        self.update_product_stock(filter, update, product_name).await
+    }
+
+    pub async fn clear_product_stock(
+        &self,
+        store_id: &ObjectId,
+        category_name: &str,
+        product_name: &str,
+    ) -> mongodb::error::Result<Option<usize>> {
+        let filter = product_filter(store_id, category_name, product_name);
+        let update = doc! { "$set": { "products.$.stock": [] } };
+        self.update_product_stock(filter, update, product_name).await
+    }
+
+    pub async fn set_low_stock_threshold(
+        &self,
+        store_id: &ObjectId,
+        category_name: &str,
+        product_name: &str,
+        threshold: Option<u32>,
+    ) -> mongodb::error::Result<bool> {
+        let filter = product_filter(store_id, category_name, product_name);
+        let update = doc! {
+            "$set": {
+                "products.$.low_stock_threshold": threshold.map(i64::from),
+                "products.$.low_stock_alerted": false,
+            }
+        };
+        let result = self.category_collection.update_one(filter, update).await?;
+        Ok(result.matched_count > 0)
+    }
+
+    pub async fn set_low_stock_alerted(
+        &self,
+        store_id: &ObjectId,
+        category_name: &str,
+        product_name: &str,
+        alerted: bool,
+    ) -> mongodb::error::Result<bool> {
+        let filter = doc! {
+            "store_id": store_id,
+            "name": category_name,
+            "products": { "$elemMatch": { "name": product_name, "low_stock_alerted": { "$ne": alerted } } },
+        };
+        let update = doc! { "$set": { "products.$.low_stock_alerted": alerted } };
+        let result = self.category_collection.update_one(filter, update).await?;
+        Ok(result.modified_count > 0)
+    }
+
+    pub async fn replace_product_stock(
+        &self,
+        category_id: &ObjectId,
+        product_id: &ObjectId,
+        current: &[String],
+        replacement: &[String],
+    ) -> mongodb::error::Result<bool> {
+        let filter = doc! {
+            "_id": category_id,
+            "products": { "$elemMatch": { "_id": product_id, "stock": current } },
+        };
+        let update = doc! { "$set": { "products.$.stock": replacement } };
+        let result = self.category_collection.update_one(filter, update).await?;
+        Ok(result.modified_count > 0)
+    }
+
+    async fn find_previous_product_stock(
+        &self,
+        filter: Document,
+        update: Document,
+        projection: Document,
+        product_name: &str,
+    ) -> mongodb::error::Result<Option<Product>> {
+        let category = self
+            .category_collection
+            .find_one_and_update(filter, update)
+            .projection(projection)
+            .return_document(ReturnDocument::Before)
+            .await?;
+
+        Ok(category.and_then(|c| c.products.into_iter().find(|p| p.name == product_name)))
+    }
+
+    async fn update_product_stock(
+        &self,
+        filter: Document,
+        update: Document,
+        product_name: &str,
+    ) -> mongodb::error::Result<Option<usize>> {
+        let category = self
+            .category_collection
+            .find_one_and_update(filter, update)
+            .projection(stock_projection(bson!([])))
+            .return_document(ReturnDocument::After)
+            .await?;
+
+        Ok(category
+            .and_then(|c| c.products.into_iter().find(|p| p.name == product_name))
+            .map(|p| p.stock_count()))
+    }
+}
+
+fn is_write_conflict(err: &mongodb::error::Error) -> bool {
+    matches!(err.kind.as_ref(), ErrorKind::Command(command) if command.code == WRITE_CONFLICT_CODE)
+}
+
+fn product_filter(store_id: &ObjectId, category_name: &str, product_name: &str) -> Document {
+    doc! {
+        "store_id": store_id,
+        "name": category_name,
+        "products.name": product_name,
+    }
+}
+
+fn capped_product_filter(
+    store_id: &ObjectId,
+    category_name: &str,
+    product_name: &str,
+    adding: usize,
+    capacity: Option<usize>,
+) -> Option<Document> {
+    let Some(capacity) = capacity else {
+        return Some(product_filter(store_id, category_name, product_name));
+    };
+    if adding > capacity {
+        return None;
+    }
+
+    // 재고가 capacity - adding 개 이하일 때만, 즉 그 위치에 코드가 없을 때만 매칭됩니다.
+    Some(doc! {
+        "store_id": store_id,
+        "name": category_name,
+        "products": {
+            "$elemMatch": {
+                "name": product_name,
+                format!("stock.{}", capacity - adding): { "$exists": false },
+            }
+        },
+    })
+}
+
+// 제품마다 재고 배열은 stock 식으로 바꿔 받고, 원래 재고 수는 counted_stock 으로 함께 받습니다.
+fn stock_projection(stock: Bson) -> Document {
+    doc! {
+        "store_id": 1,
+        "name": 1,
+        "description": 1,
+        "products": {
+            "$map": {
+                "input": "$products",
+                "as": "product",
+                "in": {
+                    "$mergeObjects": [
+                        "$$product",
+                        { "stock": stock, "counted_stock": { "$size": "$$product.stock" } },
+                    ]
+                },
+            }
+        },
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+use axum::extract::Path;
+use axum::http::{Method, StatusCode};
+use axum::{Extension, Json, Router};
+use axum::response::IntoResponse;
+use crate::auth::policy::PolicyRouter;
+use crate::auth::extractor::AuthUser;
+use crate::category::product::helper::{find_product_in_category, get_category_summary_from_store};
+use crate::category::product::restock::model::RestockSubscription;
+use crate::common::error::AppError;
+use crate::common::i18n::Message;
+use crate::common::response::ErrorResponse;
+use crate::common::types::Status;
+use crate::database::MongoRepository;
+
+pub fn restock_routes() -> Router {
+    Router::new()
+        .policy_route(Method::POST, "/api/category/:category_name/product/:product_name/notify-me", subscribe_restock)
+        .policy_route(
+            Method::DELETE,
+            "/api/category/:category_name/product/:product_name/notify-me",
+            unsubscribe_restock,
+        )
+}
+
+pub async fn subscribe_restock(
+    AuthUser { store, user, .. }: AuthUser,
+    Path((category_name, product_name)): Path<(String, String)>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    let mut category = get_category_summary_from_store(&store.object_id, &category_name, &mongo_repo).await?;
+    let product = find_product_in_category(&mut category, &product_name)?;
+
+    if product.stock_count() > 0 {
+        return Err(AppError::Conflict(Message::new("PRODUCT_ALREADY_IN_STOCK").arg(product_name)));
+    }
+
+    let subscription = RestockSubscription::new(
+        store.object_id.unwrap(),
+        user.object_id.unwrap(),
+        user.email.clone(),
+        category_name,
+        product_name.clone(),
+    );
+
+    let created = mongo_repo.subscribe_restock(subscription).await?;
+
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("RESTOCK_SUBSCRIBED").arg(product_name).localize(),
+    };
+    let status = if created { StatusCode::CREATED } else { StatusCode::OK };
+    Ok((status, Json(response)))
+}
+
+pub async fn unsubscribe_restock(
+    AuthUser { store, user, .. }: AuthUser,
+    Path((category_name, product_name)): Path<(String, String)>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    let removed = mongo_repo
+        .unsubscribe_restock(&store.object_id.unwrap(), &user.object_id.unwrap(), &category_name, &product_name)
+        .await?;
+
+    if !removed {
+        return Err(AppError::NotFound(Message::new("RESTOCK_SUBSCRIPTION_NOT_FOUND")));
+    }
+
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("RESTOCK_UNSUBSCRIBED").arg(product_name).localize(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+use crate::common::email::{escape_html, send_email, Mailer};
+use crate::common::i18n::Message;
+use crate::database::MongoRepository;
+use crate::store::model::Store;
+
+pub fn spawn_restock_notification(
+    mongo_repo: Arc<MongoRepository>,
+    mailer: Arc<dyn Mailer>,
+    store: Store,
+    category_name: String,
+    product_name: String,
+) {
+    tokio::spawn(async move {
+        let store_id = store.object_id.unwrap();
+        let locale = store.default_locale;
+        let subject = Message::new("MAIL_RESTOCK_SUBJECT").arg(&store.name).arg(&product_name).render(locale);
+        let body = format!(
+            r#"<div style="font-family: sans-serif;">
+  <h2>{}</h2>
+  <p>{}</p>
+  <p>{}</p>
+</div>"#,
+            Message::new("MAIL_RESTOCK_TITLE").render(locale),
+            Message::new("MAIL_RESTOCK_BODY")
+                .arg(escape_html(&category_name))
+                .arg(escape_html(&product_name))
+                .render(locale),
+            Message::new("MAIL_RESTOCK_HURRY").render(locale),
+        );
+
+        loop {
+            match mongo_repo.take_restock_subscription(&store_id, &category_name, &product_name).await {
+                Ok(Some(subscription)) => {
+                    if let Err(err) = send_email(&mailer, &store.sender(), vec![subscription.email], &subject, &body).await {
+                        println!("재입고 알림 메일 발송 실패 ('{}'): {}", product_name, err);
+                    }
+                }
+                Ok(None) => break,
+                Err(err) => {
+                    println!("재입고 알림 구독 조회 실패 ('{}'): {}", product_name, err);
+                    break;
+                }
+            }
+        }
+    });
+}
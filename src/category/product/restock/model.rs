@@ -0,0 +1,31 @@
+use bson::oid::ObjectId;
+use bson::serde_helpers::chrono_datetime_as_bson_datetime;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RestockSubscription {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<ObjectId>,
+    pub store_id: Option<ObjectId>,
+    pub user_id: Option<ObjectId>,
+    pub email: String,
+    pub category_name: String,
+    pub product_name: String,
+    #[serde(with = "chrono_datetime_as_bson_datetime")]
+    pub create_at: DateTime<Utc>,
+}
+
+impl RestockSubscription {
+    pub fn new(store_id: ObjectId, user_id: ObjectId, email: String, category_name: String, product_name: String) -> Self {
+        RestockSubscription {
+            object_id: None,
+            store_id: Some(store_id),
+            user_id: Some(user_id),
+            email,
+            category_name,
+            product_name,
+            create_at: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use mongodb::bson::{doc, to_bson};
+use mongodb::bson::oid::ObjectId;
+use mongodb::error::Result;
+use crate::category::product::restock::model::RestockSubscription;
+use crate::database::MongoRepository;
+
+impl MongoRepository {
+    pub async fn subscribe_restock(&self, subscription: RestockSubscription) -> Result<bool> {
+        let filter = doc! {
+            "store_id": subscription.store_id,
+            "user_id": subscription.user_id,
+            "category_name": &subscription.category_name,
+            "product_name": &subscription.product_name,
+        };
+        let update = doc! { "$setOnInsert": to_bson(&subscription)? };
+
+        let result = self.restock_subscription_collection.update_one(filter, update).upsert(true).await?;
+        Ok(result.upserted_id.is_some())
+    }
+
+    pub async fn unsubscribe_restock(
+        &self,
+        store_id: &ObjectId,
+        user_id: &ObjectId,
+        category_name: &str,
+        product_name: &str,
+    ) -> Result<bool> {
+        let filter = doc! {
+            "store_id": store_id,
+            "user_id": user_id,
+            "category_name": category_name,
+            "product_name": product_name,
+        };
+        let result = self.restock_subscription_collection.delete_one(filter).await?;
+        Ok(result.deleted_count > 0)
+    }
+
+    pub async fn take_restock_subscription(
+        &self,
+        store_id: &ObjectId,
+        category_name: &str,
+        product_name: &str,
+    ) -> Result<Option<RestockSubscription>> {
+        let filter = doc! {
+            "store_id": store_id,
+            "category_name": category_name,
+            "product_name": product_name,
+        };
+        self.restock_subscription_collection.find_one_and_delete(filter).await
+    }
+
+    pub async fn update_user_restock_email(&self, store_id: &ObjectId, user_id: &ObjectId, email: &str) -> Result<()> {
+        let filter = doc! { "store_id": store_id, "user_id": user_id };
+        let update = doc! { "$set": { "email": email } };
+        self.restock_subscription_collection.update_many(filter, update).await?;
+        Ok(())
+    }
+
+    pub async fn delete_user_restock_subscriptions(&self, store_id: &ObjectId, user_id: &ObjectId) -> Result<()> {
+        let filter = doc! { "store_id": store_id, "user_id": user_id };
+        self.restock_subscription_collection.delete_many(filter).await?;
+        Ok(())
+    }
+}
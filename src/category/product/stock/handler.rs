@@ -1,60 +1,282 @@
+use std::collections::HashSet;
 use std::sync::Arc;
-use axum::extract::Path;
-use axum::http::{HeaderMap, StatusCode};
+use axum::extract::{DefaultBodyLimit, Multipart, Path, Query};
+use axum::handler::Handler;
+use axum::http::{Method, StatusCode};
 use axum::{Extension, Json, Router};
-use axum::response::IntoResponse;
-use axum::routing::{patch};
+use axum::response::{IntoResponse, Response};
+use bson::oid::ObjectId;
+use crate::auth::policy::PolicyRouter;
+use crate::auth::extractor::Authorized;
 use crate::category::product::helper::{find_product_in_category, get_category_from_store};
+use crate::category::product::repository::MAX_PRODUCT_STOCK;
+use crate::category::product::restock::helper::spawn_restock_notification;
+use crate::category::product::stock::helper::{
+    is_valid_code, parse_stock_file, partition_duplicates, spawn_low_stock_check,
+};
+use crate::category::product::stock::model::{
+    ImportScope, StockChange, StockCountResponse, StockImportQuery, StockImportResponse, StockListResponse, UpdateThresholdRequest,
+};
+use crate::common::crypto::STOCK_CIPHER;
+use crate::common::email::Mailer;
+use crate::common::error::AppError;
+use crate::common::i18n::Message;
 use crate::common::response::ErrorResponse;
 use crate::common::types::Status;
 use crate::database::MongoRepository;
-use crate::store::helper::get_store_from_headers;
+
+const STOCK_IMPORT_BODY_LIMIT: usize = 16 * 1024 * 1024;
 
 pub fn stock_routes() -> Router {
     Router::new()
-        .route("/api/category/:category_name/product/:product_name/stock", patch(update_stock))
+        .policy_route(Method::GET, "/api/category/:category_name/product/:product_name/stock", list_stock)
+        .policy_route(Method::POST, "/api/category/:category_name/product/:product_name/stock", add_stock)
+        .policy_route(Method::DELETE, "/api/category/:category_name/product/:product_name/stock", remove_stock)
+        .policy_route(Method::POST, "/api/category/:category_name/product/:product_name/stock/clear", clear_stock)
+        .policy_route(
+            Method::PATCH,
+            "/api/category/:category_name/product/:product_name/stock/threshold",
+            update_threshold,
+        )
+        .policy_route(
+            Method::POST,
+            "/api/category/:category_name/product/:product_name/stock/import",
+            import_stock.layer(DefaultBodyLimit::max(STOCK_IMPORT_BODY_LIMIT)),
+        )
 }
 
-pub async fn update_stock(
-    headers: HeaderMap,
+pub async fn list_stock(
+    Authorized { store, .. }: Authorized,
     Path((category_name, product_name)): Path<(String, String)>,
     Extension(mongo_repo): Extension<Arc<MongoRepository>>,
-    Json(body): Json<Vec<String>>,
-) -> Result<impl IntoResponse, StatusCode> {
-    let store = match get_store_from_headers(&headers, &mongo_repo).await {
-        Ok(store) => store,
-        Err(err) => return Ok(err.into_response()),
+) -> Result<impl IntoResponse, AppError> {
+    let mut category = get_category_from_store(&store.object_id, &category_name, &mongo_repo).await?;
+    let product = find_product_in_category(&mut category, &product_name)?;
+    let stock = STOCK_CIPHER.decrypt_all(&product.stock)?;
+
+    let response = StockListResponse {
+        status: Status::Success,
+        stock_count: stock.len(),
+        stock,
     };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn add_stock(
+    Authorized { store, .. }: Authorized,
+    Path((category_name, product_name)): Path<(String, String)>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Extension(mailer): Extension<Arc<dyn Mailer>>,
+    Json(body): Json<Vec<String>>,
+) -> Result<impl IntoResponse, AppError> {
+    validate_codes(&body)?;
+
+    let store_id = store.object_id.unwrap();
+    let codes = STOCK_CIPHER.encrypt_all(&body);
+    let change = mongo_repo
+        .push_product_stock(&store_id, &category_name, &product_name, &codes, Some(MAX_PRODUCT_STOCK))
+        .await?;
+    let change = capped_stock_change(&mongo_repo, change, &store_id, &category_name, &product_name).await?;
+
+    if change.previous == 0 {
+        spawn_restock_notification(
+            mongo_repo.clone(),
+            mailer.clone(),
+            store.clone(),
+            category_name.clone(),
+            product_name.clone(),
+        );
+    }
+    spawn_low_stock_check(mongo_repo.clone(), mailer, store, category_name, product_name);
+
+    Ok(stock_count_response(change.current))
+}
+
+pub async fn remove_stock(
+    Authorized { store, .. }: Authorized,
+    Path((category_name, product_name)): Path<(String, String)>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Extension(mailer): Extension<Arc<dyn Mailer>>,
+    Json(body): Json<Vec<String>>,
+) -> Result<impl IntoResponse, AppError> {
+    validate_codes(&body)?;
+
+    let codes = STOCK_CIPHER.stored_forms(&body);
+    let stock_count = mongo_repo
+        .pull_product_stock(&store.object_id.unwrap(), &category_name, &product_name, &codes)
+        .await?;
+    let stock_count = found_stock_count(stock_count, &product_name)?;
+
+    spawn_low_stock_check(mongo_repo.clone(), mailer, store, category_name, product_name);
+
+    Ok(stock_count_response(stock_count))
+}
+
+pub async fn clear_stock(
+    Authorized { store, .. }: Authorized,
+    Path((category_name, product_name)): Path<(String, String)>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Extension(mailer): Extension<Arc<dyn Mailer>>,
+) -> Result<impl IntoResponse, AppError> {
+    let stock_count = mongo_repo
+        .clear_product_stock(&store.object_id.unwrap(), &category_name, &product_name)
+        .await?;
+    let stock_count = found_stock_count(stock_count, &product_name)?;
+
+    spawn_low_stock_check(mongo_repo.clone(), mailer, store, category_name, product_name);
+
+    Ok(stock_count_response(stock_count))
+}
+
+pub async fn import_stock(
+    Authorized { store, .. }: Authorized,
+    Path((category_name, product_name)): Path<(String, String)>,
+    Query(query): Query<StockImportQuery>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Extension(mailer): Extension<Arc<dyn Mailer>>,
+    mut multipart: Multipart,
+) -> Result<impl IntoResponse, AppError> {
+    let mut category = get_category_from_store(&store.object_id, &category_name, &mongo_repo).await?;
+    let product = find_product_in_category(&mut category, &product_name)?;
+
+    let (content, is_csv) = loop {
+        let field = multipart
+            .next_field()
+            .await
+            .map_err(|_| AppError::Validation(Message::new("UPLOAD_UNREADABLE")))?
+            .ok_or_else(|| AppError::Validation(Message::new("UPLOAD_MISSING")))?;
+
+        if field.name() != Some("file") {
+            continue;
+        }
 
-    let mut category = match get_category_from_store(&store.object_id, &category_name, &mongo_repo).await {
-        Ok(category) => category,
-        Err(err) => return Ok(err.into_response()),
+        let is_csv = field.content_type() == Some("text/csv")
+            || field.file_name().is_some_and(|name| name.to_lowercase().ends_with(".csv"));
+
+        let content = match field.bytes().await.map(|bytes| String::from_utf8(bytes.to_vec())) {
+            Ok(Ok(content)) => content,
+            _ => return Err(AppError::Validation(Message::new("UPLOAD_INVALID_ENCODING"))),
+        };
+
+        break (content, is_csv);
     };
 
-    let product = match find_product_in_category(&mut category, &product_name) {
-        Ok(p) => p,
-        Err(err) => return Ok(err.into_response()),
+    let parsed = parse_stock_file(&content, is_csv);
+
+    let existing = match query.scope {
+        ImportScope::Product => product.stock.iter().cloned().collect::<HashSet<_>>(),
+        ImportScope::Store => mongo_repo.find_store_stock_codes(&store.object_id.unwrap()).await?,
     };
+    let existing = existing
+        .iter()
+        .map(|code| STOCK_CIPHER.decrypt(code))
+        .collect::<Result<HashSet<_>, _>>()?;
+
+    let (unique, duplicates) = partition_duplicates(parsed.codes, &existing);
+    let codes: Vec<String> = unique.into_iter().map(|line| line.code).collect();
 
-    product.stock = body.clone();
-
-    match mongo_repo
-        .update_product_stock(&store.object_id.unwrap(), &category_name, &product_name, &product.stock)
-        .await
-    {
-        Ok(_) => {
-            let response = ErrorResponse {
-                status: Status::Success,
-                message: format!("제품 '{}'의 재고가 성공적으로 업데이트되었습니다.", product_name),
-            };
-            Ok((StatusCode::OK, Json(response)).into_response())
+    let change = if codes.is_empty() {
+        StockChange {
+            previous: product.stock.len(),
+            current: product.stock.len(),
         }
-        Err(_) => {
-            let error_response = ErrorResponse {
-                status: Status::Error,
-                message: "재고 업데이트에 실패하였습니다.".to_string(),
-            };
-            Ok((StatusCode::INTERNAL_SERVER_ERROR, Json(error_response)).into_response())
+    } else {
+        let store_id = store.object_id.unwrap();
+        let change = mongo_repo
+            .push_unique_product_stock(
+                &store_id,
+                &category_name,
+                &product_name,
+                &STOCK_CIPHER.encrypt_all(&codes),
+                Some(MAX_PRODUCT_STOCK),
+            )
+            .await?;
+        capped_stock_change(&mongo_repo, change, &store_id, &category_name, &product_name).await?
+    };
+
+    if change.current > change.previous {
+        if change.previous == 0 {
+            spawn_restock_notification(
+                mongo_repo.clone(),
+                mailer.clone(),
+                store.clone(),
+                category_name.clone(),
+                product_name.clone(),
+            );
         }
+        spawn_low_stock_check(mongo_repo.clone(), mailer, store, category_name, product_name);
+    }
+
+    let response = StockImportResponse {
+        status: Status::Success,
+        added: change.current - change.previous,
+        duplicates,
+        invalid: parsed.invalid,
+        stock_count: change.current,
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn update_threshold(
+    Authorized { store, .. }: Authorized,
+    Path((category_name, product_name)): Path<(String, String)>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Extension(mailer): Extension<Arc<dyn Mailer>>,
+    Json(body): Json<UpdateThresholdRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let updated = mongo_repo
+        .set_low_stock_threshold(&store.object_id.unwrap(), &category_name, &product_name, body.threshold)
+        .await?;
+
+    if !updated {
+        return Err(AppError::NotFound(Message::new("PRODUCT_NOT_FOUND").arg(product_name)));
+    }
+
+    spawn_low_stock_check(mongo_repo.clone(), mailer, store, category_name, product_name.clone());
+
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("LOW_STOCK_THRESHOLD_UPDATED").arg(product_name).localize(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+fn validate_codes(codes: &[String]) -> Result<(), AppError> {
+    if codes.is_empty() || !codes.iter().all(|code| is_valid_code(code)) {
+        return Err(AppError::Validation(Message::new("INVALID_STOCK_CODES")));
+    }
+
+    Ok(())
+}
+
+fn found_stock_count(stock_count: Option<usize>, product_name: &str) -> Result<usize, AppError> {
+    stock_count.ok_or_else(|| AppError::NotFound(Message::new("PRODUCT_NOT_FOUND").arg(product_name)))
+}
+
+// 상한에 걸려 넣지 못한 경우와 제품이 없는 경우 모두 None 이므로 제품이 있는지 다시 확인해 구분합니다.
+async fn capped_stock_change(
+    mongo_repo: &Arc<MongoRepository>,
+    change: Option<StockChange>,
+    store_id: &ObjectId,
+    category_name: &str,
+    product_name: &str,
+) -> Result<StockChange, AppError> {
+    if let Some(change) = change {
+        return Ok(change);
+    }
+
+    match mongo_repo.count_product_stock(store_id, category_name, product_name).await? {
+        Some(stock_count) => Err(AppError::Conflict(
+            Message::new("STOCK_LIMIT_EXCEEDED").arg(MAX_PRODUCT_STOCK).arg(stock_count),
+        )),
+        None => Err(AppError::NotFound(Message::new("PRODUCT_NOT_FOUND").arg(product_name))),
     }
-}
\ No newline at end of file
+}
+
+fn stock_count_response(stock_count: usize) -> Response {
+    let response = StockCountResponse {
+        status: Status::Success,
+        stock_count,
+    };
+    (StatusCode::OK, Json(response)).into_response()
+}
@@ -0,0 +1,170 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use crate::category::product::stock::model::ImportLine;
+use crate::common::email::{escape_html, send_email, Mailer};
+use crate::common::i18n::Message;
+use crate::database::MongoRepository;
+use crate::store::model::Store;
+use crate::user::model::Rank;
+
+const MAX_CODE_LENGTH: usize = 256;
+
+pub struct ParsedStockFile {
+    pub codes: Vec<ImportLine>,
+    pub invalid: Vec<ImportLine>,
+}
+
+pub fn parse_stock_file(content: &str, is_csv: bool) -> ParsedStockFile {
+    let mut codes = vec![];
+    let mut invalid = vec![];
+
+    let content = content.trim_start_matches('\u{feff}');
+
+    for (index, raw) in content.lines().enumerate() {
+        let line = index + 1;
+        let value = if is_csv { first_csv_cell(raw) } else { raw.trim() };
+
+        if value.is_empty() {
+            continue;
+        }
+
+        if is_csv && line == 1 && value.eq_ignore_ascii_case("code") {
+            continue;
+        }
+
+        let code = ImportLine {
+            line,
+            code: value.to_string(),
+        };
+
+        if is_valid_code(value) {
+            codes.push(code);
+        } else {
+            invalid.push(code);
+        }
+    }
+
+    ParsedStockFile { codes, invalid }
+}
+
+pub fn partition_duplicates(
+    codes: Vec<ImportLine>,
+    existing: &HashSet<String>,
+) -> (Vec<ImportLine>, Vec<ImportLine>) {
+    let mut seen = HashSet::new();
+    let mut unique = vec![];
+    let mut duplicates = vec![];
+
+    for code in codes {
+        if existing.contains(&code.code) || !seen.insert(code.code.clone()) {
+            duplicates.push(code);
+        } else {
+            unique.push(code);
+        }
+    }
+
+    (unique, duplicates)
+}
+
+fn first_csv_cell(raw: &str) -> &str {
+    let cell = raw.split(',').next().unwrap_or("").trim();
+    cell.strip_prefix('"')
+        .and_then(|c| c.strip_suffix('"'))
+        .unwrap_or(cell)
+        .trim()
+}
+
+pub(crate) fn is_valid_code(code: &str) -> bool {
+    !code.is_empty() && code.len() <= MAX_CODE_LENGTH && !code.chars().any(|c| c.is_whitespace() || c.is_control())
+}
+
+pub fn spawn_low_stock_check(
+    mongo_repo: Arc<MongoRepository>,
+    mailer: Arc<dyn Mailer>,
+    store: Store,
+    category_name: String,
+    product_name: String,
+) {
+    tokio::spawn(async move {
+        if let Err(err) = check_low_stock(&mongo_repo, &mailer, &store, &category_name, &product_name).await {
+            println!("재고 부족 알림 처리 실패 ('{}'): {}", product_name, err);
+        }
+    });
+}
+
+async fn check_low_stock(
+    mongo_repo: &Arc<MongoRepository>,
+    mailer: &Arc<dyn Mailer>,
+    store: &Store,
+    category_name: &str,
+    product_name: &str,
+) -> mongodb::error::Result<()> {
+    let store_id = store.object_id.unwrap();
+
+    let product = match mongo_repo
+        .find_category_summary_by_name(store_id, category_name)
+        .await?
+        .and_then(|c| c.products.into_iter().find(|p| p.name == product_name))
+    {
+        Some(product) => product,
+        None => return Ok(()),
+    };
+
+    let Some(threshold) = product.low_stock_threshold else {
+        return Ok(());
+    };
+    let stock_count = product.stock_count();
+
+    if stock_count > threshold as usize {
+        if product.low_stock_alerted {
+            mongo_repo.set_low_stock_alerted(&store_id, category_name, product_name, false).await?;
+        }
+        return Ok(());
+    }
+
+    if !mongo_repo.set_low_stock_alerted(&store_id, category_name, product_name, true).await? {
+        return Ok(());
+    }
+
+    let administrators = mongo_repo.find_users_by_rank(&store_id, Rank::Administrator).await?;
+    if administrators.is_empty() {
+        return Ok(());
+    }
+
+    let locale = store.default_locale;
+    let (subject, title) = if stock_count == 0 {
+        (
+            Message::new("MAIL_SOLD_OUT_SUBJECT").arg(&store.name).arg(product_name),
+            Message::new("MAIL_SOLD_OUT_TITLE"),
+        )
+    } else {
+        (
+            Message::new("MAIL_LOW_STOCK_SUBJECT").arg(&store.name).arg(product_name).arg(stock_count),
+            Message::new("MAIL_LOW_STOCK_TITLE"),
+        )
+    };
+    let subject = subject.render(locale);
+    let body = format!(
+        r#"<div style="font-family: sans-serif;">
+  <h2>{title}</h2>
+  <p>{status}</p>
+  <p>{action}</p>
+</div>"#,
+        title = title.render(locale),
+        status = Message::new("MAIL_LOW_STOCK_BODY")
+            .arg(escape_html(category_name))
+            .arg(escape_html(product_name))
+            .arg(stock_count)
+            .arg(threshold)
+            .render(locale),
+        action = Message::new("MAIL_LOW_STOCK_ACTION").render(locale),
+    );
+    let recipients = administrators.into_iter().map(|user| user.email).collect();
+
+    if let Err(err) = send_email(mailer, &store.sender(), recipients, &subject, &body).await {
+        println!("재고 부족 알림 메일 발송 실패 ('{}'): {}", product_name, err);
+        mongo_repo.set_low_stock_alerted(&store_id, category_name, product_name, false).await?;
+    }
+
+    Ok(())
+}
@@ -1 +1,3 @@
-pub mod handler;
\ No newline at end of file
+pub mod model;
+pub mod handler;
+pub mod helper;
\ No newline at end of file
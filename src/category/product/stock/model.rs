@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use crate::common::types::Status;
+
+// 한 번의 갱신에서 얻은 변경 전후 재고 수량입니다.
+#[derive(Debug, Clone, Copy)]
+pub struct StockChange {
+    pub previous: usize,
+    pub current: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StockCountResponse {
+    pub status: Status,
+    pub stock_count: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StockListResponse {
+    pub status: Status,
+    pub stock_count: usize,
+    pub stock: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UpdateThresholdRequest {
+    pub threshold: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum ImportScope {
+    #[default]
+    Product,
+    Store,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StockImportQuery {
+    #[serde(default)]
+    pub scope: ImportScope,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ImportLine {
+    pub line: usize,
+    pub code: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StockImportResponse {
+    pub status: Status,
+    pub added: usize,
+    pub duplicates: Vec<ImportLine>,
+    pub invalid: Vec<ImportLine>,
+    pub stock_count: usize,
+}
@@ -1,5 +1,6 @@
 use bson::doc;
 use bson::oid::ObjectId;
+use mongodb::Cursor;
 use mongodb::error::Result;
 use crate::database::MongoRepository;
 use crate::category::model::{Category};
@@ -17,8 +18,12 @@ impl MongoRepository {
         Ok(result.deleted_count > 0)
     }
 
-    pub async fn find_category_by_name(&self, store_id: ObjectId, name: &str) -> Option<Category> {
+    pub async fn find_category_by_name(&self, store_id: ObjectId, name: &str) -> Result<Option<Category>> {
         let filter = doc! { "store_id": store_id, "name": name };
-        self.category_collection.find_one(filter).await.unwrap_or(None)
+        self.category_collection.find_one(filter).await
     }
-}
\ No newline at end of file
+
+    pub async fn find_all_categories(&self) -> Result<Cursor<Category>> {
+        self.category_collection.find(doc! {}).await
+    }
+}
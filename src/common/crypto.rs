@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+use std::fmt;
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use hkdf::Hkdf;
+use hmac::{Hmac, Mac};
+use once_cell::sync::Lazy;
+use rand::RngCore;
+use sha2::Sha256;
+use crate::config::CONFIG;
+
+const ENCRYPTED_PREFIX: &str = "enc:v2:";
+const SECRET_PREFIX: &str = "sec:v1:";
+const NONCE_LENGTH: usize = 12;
+
+#[derive(Debug)]
+pub enum CryptoError {
+    UnknownKey(String),
+    Malformed,
+    Decrypt,
+}
+
+impl fmt::Display for CryptoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CryptoError::UnknownKey(key_id) => write!(f, "알 수 없는 암호화 키 ID입니다: {}", key_id),
+            CryptoError::Malformed => write!(f, "암호문 형식이 올바르지 않습니다."),
+            CryptoError::Decrypt => write!(f, "복호화에 실패하였습니다."),
+        }
+    }
+}
+
+// 설정한 키를 그대로 쓰지 않고 HKDF로 nonce 유도용과 암호화용 하위 키를 나눠 씁니다.
+// TOTP 시크릿은 재고 코드와 다른 라벨로 유도한 키로 암호화합니다.
+struct StockKey {
+    mac: [u8; 32],
+    enc: [u8; 32],
+    secret: [u8; 32],
+}
+
+impl StockKey {
+    fn derive(key: [u8; 32]) -> Self {
+        let hkdf = Hkdf::<Sha256>::new(None, &key);
+        let mut mac = [0u8; 32];
+        let mut enc = [0u8; 32];
+        let mut secret = [0u8; 32];
+        hkdf.expand(b"stock-nonce", &mut mac).expect("HKDF 출력 길이가 올바르지 않습니다.");
+        hkdf.expand(b"stock-enc", &mut enc).expect("HKDF 출력 길이가 올바르지 않습니다.");
+        hkdf.expand(b"totp-secret", &mut secret).expect("HKDF 출력 길이가 올바르지 않습니다.");
+
+        StockKey { mac, enc, secret }
+    }
+}
+
+pub struct StockCipher {
+    active_key_id: String,
+    keys: HashMap<String, StockKey>,
+}
+
+pub static STOCK_CIPHER: Lazy<StockCipher> = Lazy::new(|| {
+    StockCipher::from_config(&CONFIG.stock_encryption_keys, &CONFIG.stock_encryption_key_id)
+});
+
+impl StockCipher {
+    pub fn from_config(keys: &str, active_key_id: &str) -> Self {
+        let keys = keys
+            .split(',')
+            .filter(|entry| !entry.trim().is_empty())
+            .map(|entry| {
+                let (key_id, encoded) = entry
+                    .trim()
+                    .split_once(':')
+                    .expect("STOCK_ENCRYPTION_KEYS는 '<키 ID>:<base64 키>' 형식이어야 합니다.");
+                let key: [u8; 32] = STANDARD
+                    .decode(encoded)
+                    .ok()
+                    .and_then(|bytes| bytes.try_into().ok())
+                    .expect("암호화 키는 base64로 인코딩된 32바이트여야 합니다.");
+                (key_id.to_string(), StockKey::derive(key))
+            })
+            .collect::<HashMap<_, _>>();
+
+        if !keys.contains_key(active_key_id) {
+            panic!("STOCK_ENCRYPTION_KEY_ID '{}'에 해당하는 키가 없습니다.", active_key_id);
+        }
+
+        StockCipher {
+            active_key_id: active_key_id.to_string(),
+            keys,
+        }
+    }
+
+    pub fn encrypt(&self, plaintext: &str) -> String {
+        self.encrypt_with(&self.active_key_id, plaintext)
+    }
+
+    pub fn encrypt_all(&self, plaintexts: &[String]) -> Vec<String> {
+        plaintexts.iter().map(|plaintext| self.encrypt(plaintext)).collect()
+    }
+
+    pub fn decrypt(&self, value: &str) -> Result<String, CryptoError> {
+        match value.strip_prefix(ENCRYPTED_PREFIX) {
+            Some(payload) => self.open(payload, |key| &key.enc),
+            None => Ok(value.to_string()),
+        }
+    }
+
+    pub fn decrypt_all(&self, values: &[String]) -> Result<Vec<String>, CryptoError> {
+        values.iter().map(|value| self.decrypt(value)).collect()
+    }
+
+    pub fn stored_forms(&self, plaintexts: &[String]) -> Vec<String> {
+        plaintexts
+            .iter()
+            .flat_map(|plaintext| {
+                let mut forms: Vec<String> = self.keys.keys().map(|key_id| self.encrypt_with(key_id, plaintext)).collect();
+                forms.push(plaintext.clone());
+                forms
+            })
+            .collect()
+    }
+
+    pub fn needs_reencryption(&self, value: &str) -> bool {
+        match value.strip_prefix(ENCRYPTED_PREFIX).and_then(|payload| payload.split_once(':')) {
+            Some((key_id, _)) => key_id != self.active_key_id,
+            None => true,
+        }
+    }
+
+    // TOTP 시크릿은 값으로 조회하지 않으므로 재고 코드와 달리 매번 무작위 nonce 를 씁니다.
+    pub fn encrypt_secret(&self, plaintext: &str) -> String {
+        let key = &self.keys[&self.active_key_id];
+        let mut nonce = [0u8; NONCE_LENGTH];
+        rand::thread_rng().fill_bytes(&mut nonce);
+
+        let ciphertext = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key.secret))
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_bytes())
+            .expect("AES-GCM 암호화는 실패하지 않습니다.");
+
+        let mut payload = nonce.to_vec();
+        payload.extend(ciphertext);
+
+        format!("{}{}:{}", SECRET_PREFIX, self.active_key_id, STANDARD.encode(payload))
+    }
+
+    pub fn decrypt_secret(&self, value: &str) -> Result<String, CryptoError> {
+        match value.strip_prefix(SECRET_PREFIX) {
+            Some(payload) => self.open(payload, |key| &key.secret),
+            None => Ok(value.to_string()),
+        }
+    }
+
+    pub fn secret_needs_reencryption(&self, value: &str) -> bool {
+        match value.strip_prefix(SECRET_PREFIX).and_then(|payload| payload.split_once(':')) {
+            Some((key_id, _)) => key_id != self.active_key_id,
+            None => true,
+        }
+    }
+
+    fn open(&self, payload: &str, cipher_key: fn(&StockKey) -> &[u8; 32]) -> Result<String, CryptoError> {
+        let (key_id, encoded) = payload.split_once(':').ok_or(CryptoError::Malformed)?;
+        let key = self
+            .keys
+            .get(key_id)
+            .ok_or_else(|| CryptoError::UnknownKey(key_id.to_string()))?;
+
+        let bytes = STANDARD.decode(encoded).map_err(|_| CryptoError::Malformed)?;
+        if bytes.len() <= NONCE_LENGTH {
+            return Err(CryptoError::Malformed);
+        }
+        let (nonce, ciphertext) = bytes.split_at(NONCE_LENGTH);
+
+        let plaintext = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(cipher_key(key)))
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| CryptoError::Decrypt)?;
+
+        String::from_utf8(plaintext).map_err(|_| CryptoError::Decrypt)
+    }
+
+    // 같은 코드는 같은 키에서 항상 같은 암호문이 되도록 nonce를 평문의 HMAC에서 유도합니다.
+    // 덕분에 `$pull`, `$addToSet` 과 중복 검사가 암호문 그대로 동작합니다.
+    fn encrypt_with(&self, key_id: &str, plaintext: &str) -> String {
+        let key = &self.keys[key_id];
+        let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(&key.mac).expect("HMAC은 모든 키 길이를 허용합니다.");
+        mac.update(b"stock-nonce:");
+        mac.update(plaintext.as_bytes());
+        let digest = mac.finalize().into_bytes();
+        let nonce = &digest[..NONCE_LENGTH];
+
+        let ciphertext = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key.enc))
+            .encrypt(Nonce::from_slice(nonce), plaintext.as_bytes())
+            .expect("AES-GCM 암호화는 실패하지 않습니다.");
+
+        let mut payload = nonce.to_vec();
+        payload.extend(ciphertext);
+
+        format!("{}{}:{}", ENCRYPTED_PREFIX, key_id, STANDARD.encode(payload))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEYS: &str = "k1:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=,k2:AQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQE=";
+
+    #[test]
+    fn encrypts_deterministically_and_round_trips() {
+        let cipher = StockCipher::from_config(KEYS, "k2");
+        let encrypted = cipher.encrypt("CODE-1");
+
+        assert!(encrypted.starts_with("enc:v2:k2:"));
+        assert_eq!(encrypted, cipher.encrypt("CODE-1"));
+        assert_eq!(cipher.decrypt(&encrypted).unwrap(), "CODE-1");
+        assert!(!cipher.needs_reencryption(&encrypted));
+    }
+
+    #[test]
+    fn subkeys_differ_from_the_configured_key() {
+        let cipher = StockCipher::from_config(KEYS, "k1");
+        let key = &cipher.keys["k1"];
+
+        assert_ne!(key.mac, [0u8; 32]);
+        assert_ne!(key.enc, [0u8; 32]);
+        assert_ne!(key.mac, key.enc);
+        assert_ne!(key.secret, key.enc);
+        assert_ne!(key.secret, key.mac);
+    }
+
+    #[test]
+    fn secrets_use_random_nonces_and_their_own_key() {
+        let cipher = StockCipher::from_config(KEYS, "k2");
+        let sealed = cipher.encrypt_secret("JBSWY3DPEHPK3PXP");
+
+        assert!(sealed.starts_with("sec:v1:k2:"));
+        assert_ne!(sealed, cipher.encrypt_secret("JBSWY3DPEHPK3PXP"));
+        assert_eq!(cipher.decrypt_secret(&sealed).unwrap(), "JBSWY3DPEHPK3PXP");
+        assert_eq!(cipher.decrypt_secret("JBSWY3DPEHPK3PXP").unwrap(), "JBSWY3DPEHPK3PXP");
+        assert!(!cipher.secret_needs_reencryption(&sealed));
+        assert!(cipher.secret_needs_reencryption("JBSWY3DPEHPK3PXP"));
+
+        let as_stock = format!("enc:v2:{}", sealed.strip_prefix("sec:v1:").unwrap());
+        assert!(matches!(cipher.decrypt(&as_stock), Err(CryptoError::Decrypt)));
+    }
+
+    #[test]
+    fn stored_forms_cover_every_key_and_plaintext() {
+        let cipher = StockCipher::from_config(KEYS, "k2");
+        let forms = cipher.stored_forms(&["CODE-1".to_string()]);
+
+        assert_eq!(forms.len(), 3);
+        assert!(forms.contains(&cipher.encrypt("CODE-1")));
+        assert!(forms.contains(&cipher.encrypt_with("k1", "CODE-1")));
+        assert!(forms.contains(&"CODE-1".to_string()));
+    }
+
+    #[test]
+    fn keeps_plaintext_and_rejects_unknown_keys() {
+        let cipher = StockCipher::from_config(KEYS, "k1");
+
+        assert_eq!(cipher.decrypt("PLAIN").unwrap(), "PLAIN");
+        assert!(cipher.needs_reencryption("PLAIN"));
+        assert!(matches!(cipher.decrypt("enc:v2:k9:AAAA"), Err(CryptoError::UnknownKey(_))));
+    }
+}
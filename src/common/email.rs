@@ -1,17 +1,121 @@
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use axum::async_trait;
 use resend_rs::types::CreateEmailBaseOptions;
-use resend_rs::{Resend, Result};
+use resend_rs::Resend;
 use crate::config::CONFIG;
 
-#[allow(dead_code)]
-pub async fn send_email(to: Vec<String>, subject: &str, body: &str) -> Result<()> {
-    let resend = Resend::new(&*CONFIG.resend_api_key);
+#[derive(Debug, Clone)]
+pub struct Email {
+    pub from: String,
+    pub to: Vec<String>,
+    pub subject: String,
+    pub html: String,
+}
 
-    let from = "Acme <onboarding@resend.dev>";
+#[derive(Debug)]
+pub struct MailError(pub String);
 
-    let email = CreateEmailBaseOptions::new(from, to, subject)
-        .with_html(body);
+impl fmt::Display for MailError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
 
-    let _email = resend.emails.send(email).await?;
+impl From<resend_rs::Error> for MailError {
+    fn from(err: resend_rs::Error) -> Self {
+        MailError(err.to_string())
+    }
+}
 
-    Ok(())
-}
\ No newline at end of file
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, email: Email) -> Result<(), MailError>;
+}
+
+pub struct ResendMailer {
+    resend: Resend,
+}
+
+impl ResendMailer {
+    pub fn new(api_key: &str) -> Self {
+        ResendMailer {
+            resend: Resend::new(api_key),
+        }
+    }
+}
+
+#[async_trait]
+impl Mailer for ResendMailer {
+    async fn send(&self, email: Email) -> Result<(), MailError> {
+        let options = CreateEmailBaseOptions::new(email.from, email.to, email.subject)
+            .with_html(&email.html);
+
+        let _email = self.resend.emails.send(options).await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryMailer {
+    sent: Mutex<Vec<Email>>,
+}
+
+impl InMemoryMailer {
+    pub fn new() -> Self {
+        InMemoryMailer::default()
+    }
+
+    #[cfg(test)]
+    pub fn sent(&self) -> Vec<Email> {
+        self.sent.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl Mailer for InMemoryMailer {
+    async fn send(&self, email: Email) -> Result<(), MailError> {
+        self.sent.lock().unwrap().push(email);
+        Ok(())
+    }
+}
+
+pub fn init_mailer() -> Arc<dyn Mailer> {
+    match CONFIG.mail_driver.as_str() {
+        "memory" => Arc::new(InMemoryMailer::new()),
+        _ => Arc::new(ResendMailer::new(&CONFIG.resend_api_key)),
+    }
+}
+
+pub async fn send_email(
+    mailer: &Arc<dyn Mailer>,
+    from: &str,
+    to: Vec<String>,
+    subject: &str,
+    body: &str,
+) -> Result<(), MailError> {
+    let email = Email {
+        from: from.to_string(),
+        to,
+        subject: subject.to_string(),
+        html: body.to_string(),
+    };
+
+    mailer.send(email).await
+}
+
+pub fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,189 @@
+use std::fmt;
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use mongodb::error::{ErrorKind, WriteFailure};
+use serde::{Deserialize, Serialize};
+use crate::common::crypto::CryptoError;
+use crate::common::email::MailError;
+use crate::common::i18n::{Locale, Message};
+use crate::common::types::Status;
+
+const DUPLICATE_KEY_CODE: i32 = 11000;
+
+#[derive(Debug)]
+pub enum AppError {
+    Validation(Message),
+    Unauthorized(Message),
+    Forbidden(Message),
+    NotFound(Message),
+    Conflict(Message),
+    TooManyRequests { message: Message, retry_after: Option<i64> },
+    Database(mongodb::error::Error),
+    Crypto(CryptoError),
+    Mail(MailError),
+    Internal(Message),
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AppErrorResponse {
+    pub status: Status,
+    pub code: String,
+    pub category: String,
+    pub message: String,
+}
+
+impl AppError {
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            AppError::Validation(_) => StatusCode::BAD_REQUEST,
+            AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::Conflict(_) => StatusCode::CONFLICT,
+            AppError::TooManyRequests { .. } => StatusCode::TOO_MANY_REQUESTS,
+            AppError::Database(_) => StatusCode::SERVICE_UNAVAILABLE,
+            AppError::Crypto(_) | AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            AppError::Mail(_) => StatusCode::BAD_GATEWAY,
+        }
+    }
+
+    pub fn category(&self) -> &'static str {
+        match self {
+            AppError::Validation(_) => "VALIDATION_FAILED",
+            AppError::Unauthorized(_) => "UNAUTHORIZED",
+            AppError::Forbidden(_) => "FORBIDDEN",
+            AppError::NotFound(_) => "NOT_FOUND",
+            AppError::Conflict(_) => "CONFLICT",
+            AppError::TooManyRequests { .. } => "TOO_MANY_REQUESTS",
+            AppError::Database(_) => "DATABASE_UNAVAILABLE",
+            AppError::Crypto(_) => "CRYPTO_FAILED",
+            AppError::Mail(_) => "MAIL_DELIVERY_FAILED",
+            AppError::Internal(_) => "INTERNAL_ERROR",
+        }
+    }
+
+    // 내부 오류의 상세 내용은 로그로만 남기고 클라이언트에는 일반적인 메시지만 돌려줍니다.
+    pub fn message(&self) -> Message {
+        match self {
+            AppError::Validation(message)
+            | AppError::Unauthorized(message)
+            | AppError::Forbidden(message)
+            | AppError::NotFound(message)
+            | AppError::Conflict(message)
+            | AppError::TooManyRequests { message, .. }
+            | AppError::Internal(message) => message.clone(),
+            AppError::Database(_) | AppError::Crypto(_) | AppError::Mail(_) => Message::new(self.category()),
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::Database(err) => write!(f, "{}: {}", self.category(), err),
+            AppError::Crypto(err) => write!(f, "{}: {}", self.category(), err),
+            AppError::Mail(err) => write!(f, "{}: {}", self.category(), err),
+            _ => {
+                let message = self.message();
+                write!(f, "{}: {}", message.code(), message.render(Locale::Ko))
+            }
+        }
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let status_code = self.status_code();
+        if status_code.is_server_error() {
+            println!("{}", self);
+        }
+
+        let message = self.message();
+        let body = AppErrorResponse {
+            status: if status_code.is_server_error() { Status::Error } else { Status::Failure },
+            code: message.code().to_string(),
+            category: self.category().to_string(),
+            message: message.localize(),
+        };
+
+        match self {
+            AppError::TooManyRequests { retry_after: Some(retry_after), .. } => {
+                (status_code, [(header::RETRY_AFTER, retry_after.to_string())], Json(body)).into_response()
+            }
+            _ => (status_code, Json(body)).into_response(),
+        }
+    }
+}
+
+pub fn is_duplicate_key(err: &mongodb::error::Error) -> bool {
+    matches!(
+        err.kind.as_ref(),
+        ErrorKind::Write(WriteFailure::WriteError(write_error)) if write_error.code == DUPLICATE_KEY_CODE
+    )
+}
+
+impl From<mongodb::error::Error> for AppError {
+    fn from(err: mongodb::error::Error) -> Self {
+        if is_duplicate_key(&err) {
+            return AppError::Conflict(Message::new("DUPLICATE_RESOURCE"));
+        }
+        AppError::Database(err)
+    }
+}
+
+impl From<bson::ser::Error> for AppError {
+    fn from(err: bson::ser::Error) -> Self {
+        println!("BSON 변환 실패: {}", err);
+        AppError::Internal(Message::new("DATA_CONVERSION_FAILED"))
+    }
+}
+
+impl From<CryptoError> for AppError {
+    fn from(err: CryptoError) -> Self {
+        AppError::Crypto(err)
+    }
+}
+
+impl From<MailError> for AppError {
+    fn from(err: MailError) -> Self {
+        AppError::Mail(err)
+    }
+}
+
+impl From<bcrypt::BcryptError> for AppError {
+    fn from(_: bcrypt::BcryptError) -> Self {
+        AppError::Internal(Message::new("PASSWORD_PROCESSING_FAILED"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn body_of(err: AppError) -> (StatusCode, AppErrorResponse) {
+        let response = err.into_response();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn response_carries_message_code_and_category() {
+        let (status, body) = body_of(AppError::NotFound(Message::new("ORDER_NOT_FOUND"))).await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body.code, "ORDER_NOT_FOUND");
+        assert_eq!(body.category, "NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn internal_failures_only_expose_the_category() {
+        let (status, body) = body_of(AppError::Mail(MailError("smtp down".to_string()))).await;
+
+        assert_eq!(status, StatusCode::BAD_GATEWAY);
+        assert_eq!(body.code, "MAIL_DELIVERY_FAILED");
+        assert_eq!(body.category, "MAIL_DELIVERY_FAILED");
+        assert!(!body.message.contains("smtp down"));
+    }
+}
@@ -0,0 +1,230 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+use axum::extract::Request;
+use axum::http::header::{ACCEPT_LANGUAGE, CONTENT_LANGUAGE};
+use axum::http::HeaderValue;
+use axum::middleware::Next;
+use axum::response::Response;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use crate::common::messages::MESSAGES;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    Ko,
+    En,
+    Ja,
+}
+
+impl Locale {
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Locale::Ko => "ko",
+            Locale::En => "en",
+            Locale::Ja => "ja",
+        }
+    }
+
+    fn from_tag(tag: &str) -> Option<Locale> {
+        let primary = tag.trim().split(['-', '_']).next()?.to_ascii_lowercase();
+        match primary.as_str() {
+            "ko" => Some(Locale::Ko),
+            "en" => Some(Locale::En),
+            "ja" => Some(Locale::Ja),
+            _ => None,
+        }
+    }
+}
+
+// Accept-Language 헤더에서 q 값이 가장 높은 지원 언어를 고릅니다. 같은 q 값이면 먼저 적힌 언어가 우선합니다.
+pub fn negotiate(accept_language: &str) -> Option<Locale> {
+    let mut candidates: Vec<(Locale, f32)> = accept_language
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';');
+            let locale = Locale::from_tag(parts.next()?)?;
+            let quality = match parts.find_map(|param| param.trim().strip_prefix("q=")) {
+                Some(quality) => quality.trim().parse::<f32>().ok()?,
+                None => 1.0,
+            };
+            (quality > 0.0).then_some((locale, quality))
+        })
+        .collect();
+
+    candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+    candidates.first().map(|(locale, _)| *locale)
+}
+
+struct RequestLocale {
+    preferred: Option<Locale>,
+    store_default: Cell<Option<Locale>>,
+}
+
+tokio::task_local! {
+    static REQUEST_LOCALE: RequestLocale;
+}
+
+// 요청마다 협상한 언어를 태스크 로컬에 보관해 핸들러와 AppError 응답이 같은 언어로 메시지를 만들도록 합니다.
+pub async fn locale_middleware(request: Request, next: Next) -> Response {
+    let preferred = request
+        .headers()
+        .get(ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(negotiate);
+
+    let context = RequestLocale {
+        preferred,
+        store_default: Cell::new(None),
+    };
+
+    REQUEST_LOCALE
+        .scope(context, async move {
+            let mut response = next.run(request).await;
+            response
+                .headers_mut()
+                .insert(CONTENT_LANGUAGE, HeaderValue::from_static(current_locale().tag()));
+            response
+        })
+        .await
+}
+
+pub fn set_store_locale(locale: Locale) {
+    let _ = REQUEST_LOCALE.try_with(|context| context.store_default.set(Some(locale)));
+}
+
+// 요청한 본인에게 가는 메일은 요청 언어를 따르고, 요청 밖(백그라운드 작업 등)에서는 주어진 기본 언어를 씁니다.
+pub fn requested_locale_or(default: Locale) -> Locale {
+    REQUEST_LOCALE
+        .try_with(|context| context.preferred)
+        .ok()
+        .flatten()
+        .unwrap_or(default)
+}
+
+pub fn current_locale() -> Locale {
+    REQUEST_LOCALE
+        .try_with(|context| context.preferred.or(context.store_default.get()))
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+static CATALOG: Lazy<HashMap<&'static str, [&'static str; 3]>> = Lazy::new(|| MESSAGES.iter().copied().collect());
+
+#[derive(Debug, Clone)]
+pub struct Message {
+    code: &'static str,
+    args: Vec<String>,
+}
+
+impl Message {
+    pub fn new(code: &'static str) -> Self {
+        Message { code, args: vec![] }
+    }
+
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+
+    pub fn arg(mut self, value: impl ToString) -> Self {
+        self.args.push(value.to_string());
+        self
+    }
+
+    pub fn render(&self, locale: Locale) -> String {
+        let Some(templates) = CATALOG.get(self.code) else {
+            return self.code.to_string();
+        };
+        let template = match locale {
+            Locale::Ko => templates[0],
+            Locale::En => templates[1],
+            Locale::Ja => templates[2],
+        };
+
+        let mut rendered = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            rendered.push_str(&rest[..start]);
+            let placeholder = rest[start + 1..]
+                .find('}')
+                .and_then(|end| Some((rest[start + 1..start + 1 + end].parse::<usize>().ok()?, end)));
+
+            match placeholder {
+                Some((index, end)) => {
+                    rendered.push_str(self.args.get(index).map_or("", String::as_str));
+                    rest = &rest[start + end + 2..];
+                }
+                None => {
+                    rendered.push('{');
+                    rest = &rest[start + 1..];
+                }
+            }
+        }
+        rendered.push_str(rest);
+        rendered
+    }
+
+    pub fn localize(&self) -> String {
+        self.render(current_locale())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use super::*;
+
+    #[test]
+    fn negotiate_picks_highest_quality() {
+        assert_eq!(negotiate("ko;q=0.5, en;q=0.9, ja;q=0.7"), Some(Locale::En));
+        assert_eq!(negotiate("ja, en;q=0.9"), Some(Locale::Ja));
+        assert_eq!(negotiate("en-US,en;q=0.9,ko;q=0.8"), Some(Locale::En));
+    }
+
+    #[test]
+    fn negotiate_keeps_header_order_on_ties() {
+        assert_eq!(negotiate("ja;q=0.8, en;q=0.8"), Some(Locale::Ja));
+        assert_eq!(negotiate("en_GB, ko"), Some(Locale::En));
+    }
+
+    #[test]
+    fn negotiate_skips_wildcards_unsupported_and_rejected_tags() {
+        assert_eq!(negotiate("*"), None);
+        assert_eq!(negotiate("fr-CH, fr;q=0.9, *;q=0.5"), None);
+        assert_eq!(negotiate("fr, *;q=0.8, ko;q=0.3"), Some(Locale::Ko));
+        assert_eq!(negotiate("en;q=0, ja;q=0.1"), Some(Locale::Ja));
+        assert_eq!(negotiate("en;q=abc, ko;q=0.2"), Some(Locale::Ko));
+        assert_eq!(negotiate(""), None);
+    }
+
+    #[test]
+    fn render_fills_placeholders_per_locale() {
+        let message = Message::new("STORE_RENAMED").arg("old").arg("new");
+
+        assert_eq!(message.render(Locale::Ko), "스토어 이름 'old'에서 'new'로 성공적으로 변경되었습니다.");
+        assert_eq!(message.render(Locale::En), "Store 'old' was renamed to 'new'.");
+        assert_eq!(message.render(Locale::Ja), "ストア名を「old」から「new」に変更しました。");
+    }
+
+    #[test]
+    fn render_leaves_missing_args_empty_and_unknown_codes_as_is() {
+        assert_eq!(Message::new("STORE_RENAMED").arg("old").render(Locale::En), "Store 'old' was renamed to ''.");
+        assert_eq!(Message::new("NO_SUCH_CODE").arg("x").render(Locale::En), "NO_SUCH_CODE");
+    }
+
+    #[test]
+    fn render_does_not_reinterpret_braces_in_args() {
+        let rendered = Message::new("STORE_RENAMED").arg("{1}").arg("b").render(Locale::En);
+        assert_eq!(rendered, "Store '{1}' was renamed to 'b'.");
+    }
+
+    #[test]
+    fn catalog_codes_are_unique() {
+        let mut codes = HashSet::new();
+        for (code, _) in MESSAGES {
+            assert!(codes.insert(code), "중복된 메시지 코드: {}", code);
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use std::str::FromStr;
+use bson::oid::ObjectId;
+use crate::common::error::AppError;
+use crate::common::i18n::Message;
+
+pub fn parse_object_id(value: &str, code: &'static str) -> Result<ObjectId, AppError> {
+    ObjectId::from_str(value).map_err(|_| AppError::Validation(Message::new(code)))
+}
@@ -3,24 +3,57 @@ use jsonwebtoken::errors::Error;
 use serde::{Deserialize, Serialize};
 use std::time::{SystemTime, UNIX_EPOCH, Duration};
 use bson::oid::ObjectId;
+use hkdf::Hkdf;
+use sha2::Sha256;
 use crate::config::CONFIG;
 
-#[derive(Serialize, Deserialize, Debug)]
+// 인증 메일, 이메일 변경, 2단계 인증 토큰은 JWT_SECRET 에서 용도별로 유도한 키로 서명해
+// 한 종류의 토큰이 다른 용도(특히 액세스 토큰)로 검증되지 않도록 합니다.
+fn purpose_key(secret: &str, purpose: &str) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    Hkdf::<Sha256>::new(None, secret.as_bytes())
+        .expand(format!("jwt:{}", purpose).as_bytes(), &mut key)
+        .expect("HKDF 출력 길이가 올바르지 않습니다.");
+    key
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Claims {
     pub id: String,
     pub store_id: String,
+    pub sid: String,
+    pub jti: String,
+    pub iat: usize,
+    // 전체 로그아웃과 같은 초에 새로 로그인한 토큰을 구분할 수 있도록 발급 시각을 밀리초로도 담습니다.
+    #[serde(default)]
+    pub iat_ms: u64,
     pub exp: usize,
 }
 
-pub fn generate_jwt(object_id: ObjectId, store_id: ObjectId, secret: &str) -> String {
-    let expiration = SystemTime::now()
+impl Claims {
+    // iat_ms 가 없는 예전 토큰은 초 단위 iat 로 계산합니다.
+    pub fn issued_at_millis(&self) -> i64 {
+        if self.iat_ms > 0 {
+            self.iat_ms as i64
+        } else {
+            self.iat as i64 * 1000
+        }
+    }
+}
+
+pub fn generate_jwt(object_id: ObjectId, store_id: ObjectId, session_id: ObjectId, secret: &str) -> String {
+    let issued_at = SystemTime::now()
         .duration_since(UNIX_EPOCH)
-        .unwrap()
-        + Duration::from_secs(60 * 60 * 24 * 30);
+        .unwrap();
+    let expiration = issued_at + Duration::from_secs(CONFIG.access_token_ttl_secs as u64);
 
     let claims = Claims {
         id: object_id.to_hex(),
         store_id: store_id.to_hex(),
+        sid: session_id.to_hex(),
+        jti: ObjectId::new().to_hex(),
+        iat: issued_at.as_secs() as usize,
+        iat_ms: issued_at.as_millis() as u64,
         exp: expiration.as_secs() as usize,
     };
 
@@ -31,8 +64,147 @@ pub fn generate_jwt(object_id: ObjectId, store_id: ObjectId, secret: &str) -> St
     ).unwrap()
 }
 
-#[allow(dead_code)]
 pub fn validate_jwt(token: &str) -> Result<TokenData<Claims>, Error> {
     let decoding_key = DecodingKey::from_secret(CONFIG.jwt_secret.as_ref());
     decode::<Claims>(token, &decoding_key, &Validation::default())
-}
\ No newline at end of file
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct VerificationClaims {
+    pub id: String,
+    pub store_id: String,
+    pub email: String,
+    pub purpose: String,
+    pub exp: usize,
+}
+
+pub const EMAIL_VERIFICATION_PURPOSE: &str = "verify_email";
+pub const EMAIL_CHANGE_PURPOSE: &str = "change_email";
+
+pub fn generate_verification_jwt(object_id: ObjectId, store_id: ObjectId, email: &str, purpose: &str, secret: &str) -> String {
+    let expiration = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        + Duration::from_secs(CONFIG.email_verification_ttl_secs as u64);
+
+    let claims = VerificationClaims {
+        id: object_id.to_hex(),
+        store_id: store_id.to_hex(),
+        email: email.to_string(),
+        purpose: purpose.to_string(),
+        exp: expiration.as_secs() as usize,
+    };
+
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(&purpose_key(secret, purpose)),
+    ).unwrap()
+}
+
+pub fn validate_verification_jwt(token: &str) -> Option<VerificationClaims> {
+    [EMAIL_VERIFICATION_PURPOSE, EMAIL_CHANGE_PURPOSE].into_iter().find_map(|purpose| {
+        let decoding_key = DecodingKey::from_secret(&purpose_key(&CONFIG.jwt_secret, purpose));
+        decode::<VerificationClaims>(token, &decoding_key, &Validation::default())
+            .ok()
+            .map(|data| data.claims)
+            .filter(|claims| claims.purpose == purpose)
+    })
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChallengeClaims {
+    pub id: String,
+    pub store_id: String,
+    pub purpose: String,
+    pub exp: usize,
+}
+
+const TWO_FACTOR_PURPOSE: &str = "two_factor_challenge";
+
+pub fn generate_challenge_jwt(object_id: ObjectId, store_id: ObjectId, secret: &str) -> String {
+    let expiration = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        + Duration::from_secs(CONFIG.two_factor_challenge_ttl_secs as u64);
+
+    let claims = ChallengeClaims {
+        id: object_id.to_hex(),
+        store_id: store_id.to_hex(),
+        purpose: TWO_FACTOR_PURPOSE.to_string(),
+        exp: expiration.as_secs() as usize,
+    };
+
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(&purpose_key(secret, TWO_FACTOR_PURPOSE)),
+    ).unwrap()
+}
+
+pub fn validate_challenge_jwt(token: &str) -> Option<ChallengeClaims> {
+    let decoding_key = DecodingKey::from_secret(&purpose_key(&CONFIG.jwt_secret, TWO_FACTOR_PURPOSE));
+    decode::<ChallengeClaims>(token, &decoding_key, &Validation::default())
+        .ok()
+        .map(|data| data.claims)
+        .filter(|claims| claims.purpose == TWO_FACTOR_PURPOSE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::testing::init_config;
+
+    #[test]
+    fn tokens_only_validate_for_their_own_purpose() {
+        init_config();
+        let (user_id, store_id) = (ObjectId::new(), ObjectId::new());
+
+        let verification =
+            generate_verification_jwt(user_id, store_id, "a@example.com", EMAIL_VERIFICATION_PURPOSE, &CONFIG.jwt_secret);
+        let change = generate_verification_jwt(user_id, store_id, "b@example.com", EMAIL_CHANGE_PURPOSE, &CONFIG.jwt_secret);
+        let challenge = generate_challenge_jwt(user_id, store_id, &CONFIG.jwt_secret);
+        let access = generate_jwt(user_id, store_id, ObjectId::new(), &CONFIG.jwt_secret);
+
+        assert_eq!(validate_verification_jwt(&verification).unwrap().purpose, EMAIL_VERIFICATION_PURPOSE);
+        assert_eq!(validate_verification_jwt(&change).unwrap().purpose, EMAIL_CHANGE_PURPOSE);
+        assert!(validate_challenge_jwt(&challenge).is_some());
+        assert!(validate_jwt(&access).is_ok());
+
+        assert!(validate_jwt(&verification).is_err());
+        assert!(validate_jwt(&challenge).is_err());
+        assert!(validate_challenge_jwt(&verification).is_none());
+        assert!(validate_verification_jwt(&challenge).is_none());
+        assert!(validate_verification_jwt(&access).is_none());
+    }
+
+    #[test]
+    fn purpose_cannot_be_swapped_without_the_matching_key() {
+        init_config();
+        let claims = VerificationClaims {
+            id: ObjectId::new().to_hex(),
+            store_id: ObjectId::new().to_hex(),
+            email: "a@example.com".to_string(),
+            purpose: EMAIL_CHANGE_PURPOSE.to_string(),
+            exp: usize::MAX / 2,
+        };
+        let key = purpose_key(&CONFIG.jwt_secret, EMAIL_VERIFICATION_PURPOSE);
+        let forged = encode(&Header::default(), &claims, &EncodingKey::from_secret(&key)).unwrap();
+
+        assert!(validate_verification_jwt(&forged).is_none());
+    }
+
+    #[test]
+    fn access_tokens_carry_millisecond_issue_time() {
+        init_config();
+        let access = generate_jwt(ObjectId::new(), ObjectId::new(), ObjectId::new(), &CONFIG.jwt_secret);
+        let claims = validate_jwt(&access).unwrap().claims;
+
+        assert!(claims.iat_ms > 0);
+        assert_eq!(claims.iat_ms / 1000, claims.iat as u64);
+        assert_eq!(claims.issued_at_millis(), claims.iat_ms as i64);
+
+        let legacy = Claims { iat_ms: 0, ..claims };
+        assert_eq!(legacy.issued_at_millis(), legacy.iat as i64 * 1000);
+    }
+}
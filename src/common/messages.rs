@@ -0,0 +1,1020 @@
+// 메시지 코드별 번역 목록입니다. 순서는 [한국어, 영어, 일본어]이며 {0}, {1}은 인자 자리입니다.
+pub const MESSAGES: &[(&str, [&str; 3])] = &[
+    // 공통
+    (
+        "DATABASE_UNAVAILABLE",
+        [
+            "데이터베이스를 일시적으로 사용할 수 없습니다. 잠시 후 다시 시도해 주세요.",
+            "The database is temporarily unavailable. Please try again later.",
+            "データベースが一時的に利用できません。しばらくしてから再度お試しください。",
+        ],
+    ),
+    (
+        "DATABASE_NOT_INITIALIZED",
+        [
+            "데이터베이스가 초기화되지 않았습니다.",
+            "The database has not been initialized.",
+            "データベースが初期化されていません。",
+        ],
+    ),
+    (
+        "ROUTE_POLICY_MISSING",
+        [
+            "이 경로에 대한 접근 권한 정책이 등록되지 않았습니다.",
+            "No access policy is registered for this route.",
+            "このルートのアクセス権限ポリシーが登録されていません。",
+        ],
+    ),
+    (
+        "DUPLICATE_RESOURCE",
+        ["이미 존재하는 데이터입니다.", "The resource already exists.", "既に存在するデータです。"],
+    ),
+    (
+        "DATA_CONVERSION_FAILED",
+        ["데이터 변환에 실패하였습니다.", "Failed to convert data.", "データの変換に失敗しました。"],
+    ),
+    (
+        "CRYPTO_FAILED",
+        ["암호화 처리에 실패하였습니다.", "Encryption processing failed.", "暗号化処理に失敗しました。"],
+    ),
+    (
+        "MAIL_DELIVERY_FAILED",
+        ["메일 발송에 실패하였습니다.", "Failed to send the email.", "メールの送信に失敗しました。"],
+    ),
+    (
+        "INVALID_CURSOR",
+        ["유효하지 않은 커서입니다.", "Invalid cursor.", "無効なカーソルです。"],
+    ),
+    // 스토어
+    (
+        "STORE_NAME_REQUIRED",
+        ["스토어 이름을 제공해주세요.", "Please provide a store name.", "ストア名を指定してください。"],
+    ),
+    (
+        "STORE_NOT_FOUND",
+        ["존재하지 않는 스토어입니다.", "The store does not exist.", "存在しないストアです。"],
+    ),
+    (
+        "STORE_NAME_NOT_FOUND",
+        ["스토어 '{0}'를 찾을 수 없습니다.", "Store '{0}' was not found.", "ストア「{0}」が見つかりません。"],
+    ),
+    (
+        "STORE_NAME_TAKEN",
+        [
+            "이미 사용중인 스토어 이름입니다.",
+            "The store name is already in use.",
+            "このストア名は既に使用されています。",
+        ],
+    ),
+    (
+        "STORE_DELETED",
+        ["스토어 '{0}' 삭제 성공.", "Store '{0}' was deleted.", "ストア「{0}」を削除しました。"],
+    ),
+    (
+        "STORE_RENAME_FAILED",
+        [
+            "스토어 이름 '{0}'을 변경하는 데 실패하였습니다.",
+            "Failed to rename store '{0}'.",
+            "ストア「{0}」の名前変更に失敗しました。",
+        ],
+    ),
+    (
+        "STORE_RENAMED",
+        [
+            "스토어 이름 '{0}'에서 '{1}'로 성공적으로 변경되었습니다.",
+            "Store '{0}' was renamed to '{1}'.",
+            "ストア名を「{0}」から「{1}」に変更しました。",
+        ],
+    ),
+    (
+        "INVALID_SENDER_ADDRESS",
+        [
+            "유효하지 않은 발신자 이메일 주소입니다.",
+            "Invalid sender email address.",
+            "送信者のメールアドレスが無効です。",
+        ],
+    ),
+    (
+        "INVALID_SENDER_NAME",
+        [
+            "발신자 이름에 줄바꿈이나 <, >, \" 문자를 사용할 수 없습니다.",
+            "Sender name must not contain line breaks or <, >, \" characters.",
+            "送信者名に改行や <、>、\" を含めることはできません。",
+        ],
+    ),
+    (
+        "STORE_SENDER_UPDATED",
+        [
+            "스토어 '{0}'의 발신자 정보가 변경되었습니다.",
+            "Sender settings for store '{0}' were updated.",
+            "ストア「{0}」の送信者情報を変更しました。",
+        ],
+    ),
+    (
+        "STORE_VERIFICATION_UPDATED",
+        [
+            "스토어 '{0}'의 이메일 인증 설정이 변경되었습니다.",
+            "Email verification settings for store '{0}' were updated.",
+            "ストア「{0}」のメール認証設定を変更しました。",
+        ],
+    ),
+    (
+        "STORE_TWO_FACTOR_UPDATED",
+        [
+            "스토어 '{0}'의 2단계 인증 설정이 변경되었습니다.",
+            "Two-factor settings for store '{0}' were updated.",
+            "ストア「{0}」の二段階認証設定を変更しました。",
+        ],
+    ),
+    (
+        "STORE_LOCALE_UPDATED",
+        [
+            "스토어 '{0}'의 기본 언어가 변경되었습니다.",
+            "The default language for store '{0}' was updated.",
+            "ストア「{0}」の既定の言語を変更しました。",
+        ],
+    ),
+    // 인증
+    (
+        "INVALID_SECURITY_KEY",
+        ["유효하지 않은 보안 키입니다.", "Invalid security key.", "無効なセキュリティキーです。"],
+    ),
+    (
+        "SECURITY_KEY_REQUIRED",
+        [
+            "보안 키가 제공되지 않았습니다.",
+            "No security key was provided.",
+            "セキュリティキーが指定されていません。",
+        ],
+    ),
+    (
+        "AUTHORIZATION_REQUIRED",
+        [
+            "Authorization 헤더가 제공되지 않았습니다.",
+            "The Authorization header was not provided.",
+            "Authorization ヘッダーが指定されていません。",
+        ],
+    ),
+    (
+        "INVALID_TOKEN",
+        ["유효하지 않은 토큰입니다.", "Invalid token.", "無効なトークンです。"],
+    ),
+    (
+        "STORE_MISMATCH",
+        [
+            "잘못된 스토어 접근입니다.",
+            "The token does not belong to this store.",
+            "不正なストアへのアクセスです。",
+        ],
+    ),
+    (
+        "TOKEN_REVOKED",
+        [
+            "만료되었거나 로그아웃된 토큰입니다.",
+            "The token has expired or has been logged out.",
+            "期限切れまたはログアウト済みのトークンです。",
+        ],
+    ),
+    (
+        "ADMIN_TWO_FACTOR_REQUIRED",
+        [
+            "이 스토어에서 관리 권한을 가진 계정은 2단계 인증을 설정해야 합니다.",
+            "Accounts with management permissions in this store must enable two-factor authentication.",
+            "このストアで管理権限を持つアカウントは二段階認証を設定する必要があります。",
+        ],
+    ),
+    (
+        "INVALID_CREDENTIALS",
+        [
+            "아이디 또는 비밀번호가 올바르지 않습니다.",
+            "Incorrect user ID or password.",
+            "IDまたはパスワードが正しくありません。",
+        ],
+    ),
+    (
+        "LOGIN_LOCKED",
+        [
+            "로그인 시도가 너무 많습니다. {0}초 후 다시 시도해 주세요.",
+            "Too many login attempts. Please try again in {0} seconds.",
+            "ログイン試行回数が多すぎます。{0}秒後に再度お試しください。",
+        ],
+    ),
+    (
+        "PASSWORD_PROCESSING_FAILED",
+        [
+            "비밀번호 처리에 실패하였습니다.",
+            "Failed to process the password.",
+            "パスワードの処理に失敗しました。",
+        ],
+    ),
+    (
+        "REFRESH_TOKEN_REUSED",
+        [
+            "이미 사용된 리프레시 토큰입니다. 보안을 위해 세션이 종료되었습니다.",
+            "This refresh token has already been used. The session has been ended for your security.",
+            "このリフレッシュトークンは既に使用されています。セキュリティのためセッションを終了しました。",
+        ],
+    ),
+    (
+        "INVALID_REFRESH_TOKEN",
+        ["유효하지 않은 리프레시 토큰입니다.", "Invalid refresh token.", "無効なリフレッシュトークンです。"],
+    ),
+    (
+        "LOGGED_OUT",
+        ["로그아웃되었습니다.", "You have been logged out.", "ログアウトしました。"],
+    ),
+    (
+        "LOGGED_OUT_EVERYWHERE",
+        [
+            "모든 기기에서 로그아웃되었습니다.",
+            "You have been logged out on all devices.",
+            "すべてのデバイスからログアウトしました。",
+        ],
+    ),
+    (
+        "PASSWORD_RESET_REQUESTED",
+        [
+            "가입된 이메일이라면 비밀번호 재설정 링크가 발송됩니다.",
+            "If the email is registered, a password reset link will be sent.",
+            "登録済みのメールアドレスであれば、パスワード再設定リンクが送信されます。",
+        ],
+    ),
+    (
+        "INVALID_RESET_LINK",
+        [
+            "유효하지 않거나 만료된 재설정 링크입니다.",
+            "The reset link is invalid or has expired.",
+            "再設定リンクが無効か、期限切れです。",
+        ],
+    ),
+    (
+        "PASSWORD_CHANGED",
+        [
+            "비밀번호가 변경되었습니다. 다시 로그인해 주세요.",
+            "Your password has been changed. Please log in again.",
+            "パスワードを変更しました。再度ログインしてください。",
+        ],
+    ),
+    (
+        "TWO_FACTOR_ALREADY_ENABLED",
+        [
+            "이미 2단계 인증이 활성화되어 있습니다.",
+            "Two-factor authentication is already enabled.",
+            "二段階認証は既に有効になっています。",
+        ],
+    ),
+    (
+        "TWO_FACTOR_SETUP_NOT_STARTED",
+        [
+            "진행 중인 2단계 인증 설정이 없습니다.",
+            "There is no two-factor setup in progress.",
+            "進行中の二段階認証の設定がありません。",
+        ],
+    ),
+    (
+        "TWO_FACTOR_NOT_ENABLED",
+        [
+            "2단계 인증이 활성화되어 있지 않습니다.",
+            "Two-factor authentication is not enabled.",
+            "二段階認証が有効になっていません。",
+        ],
+    ),
+    (
+        "ADMIN_TWO_FACTOR_LOCKED",
+        [
+            "이 스토어에서 관리 권한을 가진 계정은 2단계 인증을 해제할 수 없습니다.",
+            "Accounts with management permissions in this store cannot disable two-factor authentication.",
+            "このストアで管理権限を持つアカウントは二段階認証を解除できません。",
+        ],
+    ),
+    (
+        "TWO_FACTOR_DISABLED",
+        [
+            "2단계 인증이 해제되었습니다.",
+            "Two-factor authentication has been disabled.",
+            "二段階認証を解除しました。",
+        ],
+    ),
+    (
+        "INVALID_TWO_FACTOR_CHALLENGE",
+        [
+            "유효하지 않거나 만료된 인증 요청입니다. 다시 로그인해 주세요.",
+            "The authentication request is invalid or has expired. Please log in again.",
+            "認証リクエストが無効か、期限切れです。再度ログインしてください。",
+        ],
+    ),
+    (
+        "INVALID_TWO_FACTOR_CODE",
+        [
+            "인증 코드가 올바르지 않습니다.",
+            "The verification code is incorrect.",
+            "認証コードが正しくありません。",
+        ],
+    ),
+    (
+        "USER_UNLOCKED",
+        [
+            "유저 '{0}'의 로그인 잠금이 해제되었습니다.",
+            "The login lock for user '{0}' has been released.",
+            "ユーザー「{0}」のログインロックを解除しました。",
+        ],
+    ),
+    // 유저
+    (
+        "INVALID_USER_ID",
+        ["유효하지 않은 유저 ID 형식입니다.", "Invalid user ID format.", "ユーザーIDの形式が無効です。"],
+    ),
+    (
+        "USER_NOT_FOUND",
+        ["유저를 찾을 수 없습니다.", "User not found.", "ユーザーが見つかりません。"],
+    ),
+    (
+        "INVALID_USER_RECORD",
+        ["유저 정보가 유효하지 않습니다.", "The user record is invalid.", "ユーザー情報が無効です。"],
+    ),
+    (
+        "INVALID_EMAIL",
+        ["유효하지 않은 이메일 주소입니다.", "Invalid email address.", "無効なメールアドレスです。"],
+    ),
+    (
+        "PASSWORD_MISMATCH",
+        [
+            "비밀번호와 비밀번호 확인이 일치하지 않습니다.",
+            "Password and password confirmation do not match.",
+            "パスワードと確認用パスワードが一致しません。",
+        ],
+    ),
+    (
+        "EMAIL_TAKEN",
+        [
+            "이미 가입된 이메일입니다.",
+            "This email address is already registered.",
+            "このメールアドレスは既に登録されています。",
+        ],
+    ),
+    (
+        "USER_ID_TAKEN",
+        [
+            "이미 사용중인 유저 아이디입니다.",
+            "This user ID is already in use.",
+            "このユーザーIDは既に使用されています。",
+        ],
+    ),
+    (
+        "EMAIL_NOT_VERIFIED",
+        [
+            "이메일 인증을 완료한 후 구매할 수 있습니다.",
+            "Please verify your email address before purchasing.",
+            "購入するにはメール認証を完了してください。",
+        ],
+    ),
+    (
+        "INVALID_VERIFICATION_LINK",
+        [
+            "유효하지 않거나 만료된 인증 링크입니다.",
+            "The verification link is invalid or has expired.",
+            "認証リンクが無効か、期限切れです。",
+        ],
+    ),
+    (
+        "EMAIL_VERIFIED",
+        [
+            "이메일 인증이 완료되었습니다.",
+            "Your email address has been verified.",
+            "メール認証が完了しました。",
+        ],
+    ),
+    (
+        "EMAIL_ALREADY_VERIFIED",
+        [
+            "이미 인증된 이메일입니다.",
+            "This email address is already verified.",
+            "このメールアドレスは既に認証されています。",
+        ],
+    ),
+    (
+        "VERIFICATION_RESEND_COOLDOWN",
+        [
+            "인증 메일은 {0}초에 한 번만 요청할 수 있습니다.",
+            "You can request a verification email only once every {0} seconds.",
+            "認証メールは{0}秒に1回のみリクエストできます。",
+        ],
+    ),
+    (
+        "VERIFICATION_EMAIL_RESENT",
+        [
+            "인증 메일을 다시 발송하였습니다.",
+            "The verification email has been resent.",
+            "認証メールを再送信しました。",
+        ],
+    ),
+    (
+        "INVALID_CURRENT_PASSWORD",
+        [
+            "현재 비밀번호가 올바르지 않습니다.",
+            "The current password is incorrect.",
+            "現在のパスワードが正しくありません。",
+        ],
+    ),
+    (
+        "EMAIL_UNCHANGED",
+        [
+            "새 이메일 주소가 현재 이메일 주소와 같습니다.",
+            "The new email address is the same as the current one.",
+            "新しいメールアドレスが現在のメールアドレスと同じです。",
+        ],
+    ),
+    (
+        "EMAIL_CHANGED",
+        [
+            "이메일 주소가 변경되었습니다.",
+            "Your email address has been changed.",
+            "メールアドレスを変更しました。",
+        ],
+    ),
+    (
+        "ACCOUNT_DELETED",
+        [
+            "계정이 삭제되었습니다.",
+            "Your account has been deleted.",
+            "アカウントを削除しました。",
+        ],
+    ),
+    (
+        "ACCOUNT_SUSPENDED",
+        [
+            "정지된 계정입니다. 스토어 관리자에게 문의해 주세요.",
+            "This account has been suspended. Please contact the store administrator.",
+            "このアカウントは停止されています。ストアの管理者にお問い合わせください。",
+        ],
+    ),
+    (
+        "CANNOT_MANAGE_SELF",
+        [
+            "자신의 계정 등급, 역할이나 정지 상태는 변경할 수 없습니다.",
+            "You cannot change the rank, roles or suspension of your own account.",
+            "自分のアカウントのランク、ロールや停止状態は変更できません。",
+        ],
+    ),
+    (
+        "ADMINISTRATOR_ALREADY_EXISTS",
+        [
+            "이 스토어에는 이미 관리자가 있습니다.",
+            "This store already has an administrator.",
+            "このストアには既に管理者がいます。",
+        ],
+    ),
+    (
+        "INVALID_SESSION_ID",
+        ["유효하지 않은 세션 ID입니다.", "Invalid session ID.", "無効なセッションIDです。"],
+    ),
+    (
+        "SESSION_NOT_FOUND",
+        ["세션을 찾을 수 없습니다.", "The session was not found.", "セッションが見つかりません。"],
+    ),
+    (
+        "SESSION_REVOKED",
+        ["세션이 종료되었습니다.", "The session has been signed out.", "セッションを終了しました。"],
+    ),
+    (
+        "CANNOT_MANAGE_PRIVILEGED_USER",
+        [
+            "자신에게 없는 권한을 가진 유저는 관리할 수 없습니다.",
+            "You cannot manage a user who has permissions you do not have.",
+            "自分にない権限を持つユーザーは管理できません。",
+        ],
+    ),
+    // 권한, 역할
+    (
+        "PERMISSION_DENIED",
+        ["'{0}' 권한이 없습니다.", "You do not have the '{0}' permission.", "「{0}」の権限がありません。"],
+    ),
+    (
+        "INVALID_ROLE_NAME",
+        [
+            "역할 이름은 32자 이하의 영문, 숫자, '-', '_'로만 만들 수 있습니다.",
+            "Role names must be at most 32 characters of letters, digits, '-' or '_'.",
+            "ロール名は32文字以内の英数字、「-」、「_」のみ使用できます。",
+        ],
+    ),
+    (
+        "ROLE_NAME_TAKEN",
+        [
+            "이미 존재하거나 기본 역할과 겹치는 역할 이름입니다.",
+            "A role with this name already exists or it is reserved for a built-in role.",
+            "このロール名は既に存在するか、組み込みロールと重複しています。",
+        ],
+    ),
+    (
+        "ROLE_NOT_FOUND",
+        ["역할 '{0}'를 찾을 수 없습니다.", "Role '{0}' was not found.", "ロール「{0}」が見つかりません。"],
+    ),
+    (
+        "ROLE_DELETED",
+        ["역할 '{0}' 삭제 성공.", "Role '{0}' was deleted.", "ロール「{0}」を削除しました。"],
+    ),
+    (
+        "INVALID_API_KEY",
+        [
+            "유효하지 않거나 만료된 API 키입니다.",
+            "The API key is invalid or has expired.",
+            "無効または期限切れのAPIキーです。",
+        ],
+    ),
+    (
+        "API_KEY_NOT_ALLOWED",
+        [
+            "API 키로는 API 키를 관리할 수 없습니다.",
+            "API keys cannot be used to manage API keys.",
+            "APIキーではAPIキーを管理できません。",
+        ],
+    ),
+    (
+        "API_KEY_NOT_ACCEPTED",
+        [
+            "이 경로는 API 키를 받지 않습니다. 로그인 토큰을 사용하세요.",
+            "API key not accepted on this route. Use a login token instead.",
+            "このルートではAPIキーを使用できません。ログイントークンを使用してください。",
+        ],
+    ),
+    (
+        "INVALID_API_KEY_NAME",
+        [
+            "API 키 이름은 1~64자여야 합니다.",
+            "API key names must be between 1 and 64 characters.",
+            "APIキー名は1〜64文字である必要があります。",
+        ],
+    ),
+    (
+        "INVALID_API_KEY_SCOPES",
+        [
+            "API 키에는 하나 이상의 권한이 필요하며 'api-keys:manage' 권한은 줄 수 없습니다.",
+            "API keys need at least one scope and cannot be given 'api-keys:manage'.",
+            "APIキーには一つ以上の権限が必要で、「api-keys:manage」は付与できません。",
+        ],
+    ),
+    (
+        "INVALID_API_KEY_EXPIRY",
+        [
+            "API 키 만료 시각은 현재보다 뒤여야 합니다.",
+            "The API key expiry must be in the future.",
+            "APIキーの有効期限は現在より後である必要があります。",
+        ],
+    ),
+    (
+        "INVALID_API_KEY_ID",
+        ["유효하지 않은 API 키 ID입니다.", "Invalid API key ID.", "無効なAPIキーIDです。"],
+    ),
+    (
+        "API_KEY_NOT_FOUND",
+        ["API 키를 찾을 수 없습니다.", "The API key was not found.", "APIキーが見つかりません。"],
+    ),
+    (
+        "API_KEY_REVOKED",
+        ["API 키가 폐기되었습니다.", "The API key has been revoked.", "APIキーを無効化しました。"],
+    ),
+    // 카테고리, 제품
+    (
+        "CATEGORY_NAME_TAKEN",
+        [
+            "이미 존재하는 카테고리 이름입니다.",
+            "A category with this name already exists.",
+            "このカテゴリ名は既に存在します。",
+        ],
+    ),
+    (
+        "CATEGORY_NOT_FOUND",
+        ["카테고리 '{0}'를 찾을 수 없습니다.", "Category '{0}' was not found.", "カテゴリ「{0}」が見つかりません。"],
+    ),
+    (
+        "CATEGORY_DELETED",
+        ["카테고리 '{0}' 삭제 성공.", "Category '{0}' was deleted.", "カテゴリ「{0}」を削除しました。"],
+    ),
+    (
+        "PRODUCT_ALREADY_EXISTS",
+        ["이미 '{0}' 제품이 존재합니다.", "Product '{0}' already exists.", "商品「{0}」は既に存在します。"],
+    ),
+    (
+        "INVALID_PRODUCT_ID",
+        ["유효하지 않은 제품 ID입니다.", "Invalid product ID.", "無効な商品IDです。"],
+    ),
+    (
+        "PRODUCT_DELETED",
+        ["제품 '{0}' 삭제 성공.", "Product '{0}' was deleted.", "商品「{0}」を削除しました。"],
+    ),
+    (
+        "PRODUCT_NOT_FOUND",
+        ["제품 '{0}'를 찾을 수 없습니다.", "Product '{0}' was not found.", "商品「{0}」が見つかりません。"],
+    ),
+    (
+        "STOCK_LIMIT_EXCEEDED",
+        [
+            "제품 하나에는 재고를 {0}개까지만 등록할 수 있습니다. (현재 {1}개)",
+            "A product can hold at most {0} stock codes. (currently {1})",
+            "商品ごとに登録できる在庫は{0}個までです。(現在{1}個)",
+        ],
+    ),
+    (
+        "PRODUCT_ALREADY_IN_STOCK",
+        [
+            "제품 '{0}'는 이미 재고가 있습니다.",
+            "Product '{0}' is already in stock.",
+            "商品「{0}」は既に在庫があります。",
+        ],
+    ),
+    (
+        "RESTOCK_SUBSCRIBED",
+        [
+            "제품 '{0}'가 재입고되면 메일로 알려드립니다.",
+            "We will email you when '{0}' is back in stock.",
+            "商品「{0}」が再入荷したらメールでお知らせします。",
+        ],
+    ),
+    (
+        "RESTOCK_SUBSCRIPTION_NOT_FOUND",
+        [
+            "재입고 알림 신청 내역을 찾을 수 없습니다.",
+            "No restock notification request was found.",
+            "再入荷通知の申し込みが見つかりません。",
+        ],
+    ),
+    (
+        "RESTOCK_UNSUBSCRIBED",
+        [
+            "제품 '{0}'의 재입고 알림 신청을 취소하였습니다.",
+            "Your restock notification for '{0}' has been cancelled.",
+            "商品「{0}」の再入荷通知を取り消しました。",
+        ],
+    ),
+    // 재고
+    (
+        "UPLOAD_UNREADABLE",
+        [
+            "업로드 파일을 읽을 수 없습니다.",
+            "The uploaded file could not be read.",
+            "アップロードされたファイルを読み込めません。",
+        ],
+    ),
+    (
+        "UPLOAD_MISSING",
+        ["업로드된 파일이 없습니다.", "No file was uploaded.", "ファイルがアップロードされていません。"],
+    ),
+    (
+        "UPLOAD_INVALID_ENCODING",
+        [
+            "파일은 UTF-8 텍스트 또는 CSV여야 합니다.",
+            "The file must be UTF-8 text or CSV.",
+            "ファイルはUTF-8のテキストまたはCSVである必要があります。",
+        ],
+    ),
+    (
+        "INVALID_STOCK_CODES",
+        [
+            "재고 코드 목록이 비어있거나 빈 코드, 공백이 들어간 코드, 너무 긴 코드가 포함되어 있습니다.",
+            "The stock code list is empty or contains blank, whitespace-containing or overlong codes.",
+            "在庫コードのリストが空か、空のコード、空白を含むコード、長すぎるコードが含まれています。",
+        ],
+    ),
+    (
+        "LOW_STOCK_THRESHOLD_UPDATED",
+        [
+            "제품 '{0}'의 재고 알림 기준이 변경되었습니다.",
+            "The low-stock threshold for '{0}' has been updated.",
+            "商品「{0}」の在庫アラート基準を変更しました。",
+        ],
+    ),
+    // 주문, 예약
+    (
+        "INVALID_QUANTITY",
+        [
+            "구매 수량은 1개 이상이어야 합니다.",
+            "The purchase quantity must be at least 1.",
+            "購入数量は1以上である必要があります。",
+        ],
+    ),
+    (
+        "OUT_OF_STOCK",
+        [
+            "제품 '{0}'의 재고가 부족합니다.",
+            "Product '{0}' does not have enough stock.",
+            "商品「{0}」の在庫が不足しています。",
+        ],
+    ),
+    (
+        "INVALID_ORDER_ID",
+        ["유효하지 않은 주문 ID 형식입니다.", "Invalid order ID format.", "注文IDの形式が無効です。"],
+    ),
+    (
+        "ORDER_NOT_FOUND",
+        ["주문을 찾을 수 없습니다.", "Order not found.", "注文が見つかりません。"],
+    ),
+    (
+        "RECEIPT_RESENT",
+        ["영수증을 다시 발송하였습니다.", "The receipt has been resent.", "領収書を再送信しました。"],
+    ),
+    (
+        "INVALID_RESERVATION_ID",
+        [
+            "유효하지 않은 예약 ID 형식입니다.",
+            "Invalid reservation ID format.",
+            "予約IDの形式が無効です。",
+        ],
+    ),
+    (
+        "RESERVATION_NOT_FOUND",
+        [
+            "진행 중인 예약을 찾을 수 없거나 이미 만료되었습니다.",
+            "No pending reservation was found, or it has already expired.",
+            "進行中の予約が見つからないか、既に期限切れです。",
+        ],
+    ),
+    // 메일
+    (
+        "MAIL_NO_REPLY",
+        [
+            "본 메일은 발신 전용입니다.",
+            "This is a send-only address. Please do not reply.",
+            "このメールは送信専用です。",
+        ],
+    ),
+    (
+        "MAIL_IGNORE_IF_NOT_REQUESTED",
+        [
+            "요청하지 않으셨다면 이 메일을 무시해 주세요.",
+            "If you didn't request this, you can ignore this email.",
+            "お心当たりがない場合は、このメールを無視してください。",
+        ],
+    ),
+    (
+        "MAIL_LINK_VALID_HOURS",
+        [
+            "링크는 {0}시간 동안 유효합니다.",
+            "This link is valid for {0} hours.",
+            "リンクの有効期限は{0}時間です。",
+        ],
+    ),
+    (
+        "MAIL_RECEIPT_SUBJECT",
+        [
+            "[{0}] '{1}' 구매 영수증",
+            "[{0}] Receipt for '{1}'",
+            "[{0}]「{1}」購入レシート",
+        ],
+    ),
+    (
+        "MAIL_RECEIPT_TITLE",
+        [
+            "{0} 구매 영수증",
+            "{0} purchase receipt",
+            "{0} 購入レシート",
+        ],
+    ),
+    (
+        "MAIL_RECEIPT_INTRO",
+        [
+            "주문해 주셔서 감사합니다. 구매하신 상품의 코드는 아래와 같습니다.",
+            "Thank you for your order. Your product codes are listed below.",
+            "ご注文ありがとうございます。ご購入いただいた商品のコードは以下のとおりです。",
+        ],
+    ),
+    (
+        "MAIL_RECEIPT_ORDER_ID",
+        [
+            "주문 번호",
+            "Order number",
+            "注文番号",
+        ],
+    ),
+    (
+        "MAIL_RECEIPT_PRODUCT",
+        [
+            "상품",
+            "Product",
+            "商品",
+        ],
+    ),
+    (
+        "MAIL_RECEIPT_QUANTITY",
+        [
+            "수량",
+            "Quantity",
+            "数量",
+        ],
+    ),
+    (
+        "MAIL_RECEIPT_UNIT_PRICE",
+        [
+            "단가",
+            "Unit price",
+            "単価",
+        ],
+    ),
+    (
+        "MAIL_RECEIPT_TOTAL_PRICE",
+        [
+            "합계",
+            "Total",
+            "合計",
+        ],
+    ),
+    (
+        "MAIL_RECEIPT_ORDERED_AT",
+        [
+            "주문 일시",
+            "Ordered at",
+            "注文日時",
+        ],
+    ),
+    (
+        "MAIL_RECEIPT_CODES",
+        [
+            "상품 코드",
+            "Product codes",
+            "商品コード",
+        ],
+    ),
+    (
+        "MAIL_RESTOCK_SUBJECT",
+        [
+            "[{0}] '{1}' 재입고 알림",
+            "[{0}] '{1}' is back in stock",
+            "[{0}]「{1}」再入荷のお知らせ",
+        ],
+    ),
+    (
+        "MAIL_RESTOCK_TITLE",
+        [
+            "재입고 알림",
+            "Back in stock",
+            "再入荷のお知らせ",
+        ],
+    ),
+    (
+        "MAIL_RESTOCK_BODY",
+        [
+            "기다리시던 <strong>{0} / {1}</strong> 제품이 다시 입고되었습니다.",
+            "<strong>{0} / {1}</strong>, which you were waiting for, is back in stock.",
+            "お待ちいただいていた <strong>{0} / {1}</strong> が再入荷しました。",
+        ],
+    ),
+    (
+        "MAIL_RESTOCK_HURRY",
+        [
+            "재고가 한정되어 있으니 서둘러 확인해 주세요.",
+            "Stock is limited, so check it out soon.",
+            "在庫に限りがありますので、お早めにご確認ください。",
+        ],
+    ),
+    (
+        "MAIL_SOLD_OUT_SUBJECT",
+        [
+            "[{0}] '{1}' 품절",
+            "[{0}] '{1}' is sold out",
+            "[{0}]「{1}」品切れ",
+        ],
+    ),
+    (
+        "MAIL_SOLD_OUT_TITLE",
+        [
+            "품절 알림",
+            "Sold out",
+            "品切れのお知らせ",
+        ],
+    ),
+    (
+        "MAIL_LOW_STOCK_SUBJECT",
+        [
+            "[{0}] '{1}' 재고 부족 ({2}개 남음)",
+            "[{0}] '{1}' is running low ({2} left)",
+            "[{0}]「{1}」在庫不足（残り{2}個）",
+        ],
+    ),
+    (
+        "MAIL_LOW_STOCK_TITLE",
+        [
+            "재고 부족 알림",
+            "Low stock",
+            "在庫不足のお知らせ",
+        ],
+    ),
+    (
+        "MAIL_LOW_STOCK_BODY",
+        [
+            "<strong>{0} / {1}</strong> 제품의 재고가 {2}개 남았습니다. (알림 기준: {3}개)",
+            "<strong>{0} / {1}</strong> has {2} left in stock. (Alert threshold: {3})",
+            "<strong>{0} / {1}</strong> の在庫が残り{2}個です。（通知基準: {3}個）",
+        ],
+    ),
+    (
+        "MAIL_LOW_STOCK_ACTION",
+        [
+            "재고를 보충해 주세요.",
+            "Please restock this product.",
+            "在庫を補充してください。",
+        ],
+    ),
+    (
+        "MAIL_VERIFY_SUBJECT",
+        [
+            "[{0}] 이메일 주소를 인증해 주세요",
+            "[{0}] Please verify your email address",
+            "[{0}] メールアドレスを認証してください",
+        ],
+    ),
+    (
+        "MAIL_VERIFY_TITLE",
+        [
+            "이메일 인증",
+            "Email verification",
+            "メール認証",
+        ],
+    ),
+    (
+        "MAIL_VERIFY_BODY",
+        [
+            "{0}님, 가입해 주셔서 감사합니다. 아래 링크를 눌러 이메일 주소를 인증해 주세요.",
+            "Hi {0}, thanks for signing up. Click the link below to verify your email address.",
+            "{0}様、ご登録ありがとうございます。以下のリンクからメールアドレスを認証してください。",
+        ],
+    ),
+    (
+        "MAIL_VERIFY_LINK",
+        [
+            "이메일 인증하기",
+            "Verify email",
+            "メールアドレスを認証する",
+        ],
+    ),
+    (
+        "MAIL_EMAIL_CHANGE_SUBJECT",
+        [
+            "[{0}] 새 이메일 주소를 인증해 주세요",
+            "[{0}] Please verify your new email address",
+            "[{0}] 新しいメールアドレスを認証してください",
+        ],
+    ),
+    (
+        "MAIL_EMAIL_CHANGE_TITLE",
+        [
+            "이메일 변경 인증",
+            "Confirm email change",
+            "メールアドレス変更の認証",
+        ],
+    ),
+    (
+        "MAIL_EMAIL_CHANGE_BODY",
+        [
+            "{0}님, 계정 이메일을 이 주소로 변경하려면 아래 링크를 눌러 인증해 주세요.",
+            "Hi {0}, click the link below to change your account email to this address.",
+            "{0}様、アカウントのメールアドレスをこのアドレスに変更するには、以下のリンクから認証してください。",
+        ],
+    ),
+    (
+        "MAIL_EMAIL_CHANGE_LINK",
+        [
+            "이메일 변경 인증하기",
+            "Confirm email change",
+            "メールアドレスの変更を認証する",
+        ],
+    ),
+    (
+        "MAIL_RESET_SUBJECT",
+        [
+            "[{0}] 비밀번호 재설정 안내",
+            "[{0}] Reset your password",
+            "[{0}] パスワード再設定のご案内",
+        ],
+    ),
+    (
+        "MAIL_RESET_TITLE",
+        [
+            "비밀번호 재설정",
+            "Password reset",
+            "パスワード再設定",
+        ],
+    ),
+    (
+        "MAIL_RESET_BODY",
+        [
+            "{0}님, 아래 링크에서 새 비밀번호를 설정해 주세요.",
+            "Hi {0}, set a new password using the link below.",
+            "{0}様、以下のリンクから新しいパスワードを設定してください。",
+        ],
+    ),
+    (
+        "MAIL_RESET_LINK",
+        [
+            "비밀번호 재설정하기",
+            "Reset password",
+            "パスワードを再設定する",
+        ],
+    ),
+    (
+        "MAIL_RESET_VALID_MINUTES",
+        [
+            "링크는 {0}분 동안 한 번만 사용할 수 있습니다.",
+            "This link can be used once within {0} minutes.",
+            "リンクは{0}分以内に一度だけ使用できます。",
+        ],
+    ),
+];
@@ -1,4 +1,13 @@
 pub mod jwt;
 pub mod types;
 pub mod response;
-pub mod email;
\ No newline at end of file
+pub mod email;
+pub mod pagination;
+pub mod crypto;
+pub mod error;
+pub mod totp;
+pub mod i18n;
+pub mod messages;
+pub mod id;
+#[cfg(test)]
+pub mod testing;
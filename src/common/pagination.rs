@@ -0,0 +1,29 @@
+use std::str::FromStr;
+use bson::oid::ObjectId;
+use crate::common::error::AppError;
+use crate::common::i18n::Message;
+
+const DEFAULT_PAGE_LIMIT: i64 = 20;
+const MAX_PAGE_LIMIT: i64 = 100;
+
+pub fn page_limit(limit: Option<i64>) -> i64 {
+    limit.unwrap_or(DEFAULT_PAGE_LIMIT).clamp(1, MAX_PAGE_LIMIT)
+}
+
+pub fn parse_cursor(cursor: &Option<String>) -> Result<Option<ObjectId>, AppError> {
+    cursor
+        .as_deref()
+        .map(ObjectId::from_str)
+        .transpose()
+        .map_err(|_| AppError::Validation(Message::new("INVALID_CURSOR")))
+}
+
+pub fn split_page<T>(mut items: Vec<T>, limit: i64, id: impl Fn(&T) -> Option<ObjectId>) -> (Vec<T>, Option<String>) {
+    if items.len() as i64 > limit {
+        items.truncate(limit as usize);
+        let next_cursor = items.last().and_then(id).map(|id| id.to_hex());
+        (items, next_cursor)
+    } else {
+        (items, None)
+    }
+}
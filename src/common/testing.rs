@@ -0,0 +1,24 @@
+use std::env;
+use std::sync::Once;
+
+static INIT: Once = Once::new();
+
+// CONFIG 는 처음 접근할 때 환경 변수를 읽으므로, 테스트는 CONFIG 를 쓰기 전에 이 함수를 먼저 호출합니다.
+pub fn init_config() {
+    INIT.call_once(|| {
+        let defaults = [
+            ("SERVER_PORT", "0"),
+            ("DATABASE_URL", "mongodb://127.0.0.1:9/?serverSelectionTimeoutMS=100&connectTimeoutMS=100"),
+            ("DATABASE_NAME", "vronix_test"),
+            ("JWT_SECRET", "test-jwt-secret"),
+            ("RESEND_API_KEY", "re_test"),
+            ("VRONIX_SECURITY_KEY", "test-security-key"),
+            ("MAIL_DRIVER", "memory"),
+            ("STOCK_ENCRYPTION_KEYS", "k1:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA="),
+            ("STOCK_ENCRYPTION_KEY_ID", "k1"),
+        ];
+        for (key, value) in defaults {
+            env::set_var(key, value);
+        }
+    });
+}
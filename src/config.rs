@@ -1,4 +1,5 @@
 use std::env;
+use std::net::IpAddr;
 use dotenv::dotenv;
 use once_cell::sync::Lazy;
 
@@ -9,6 +10,26 @@ pub struct Config {
     pub jwt_secret: String,
     pub resend_api_key: String,
     pub vronix_security_key: String,
+    pub mail_driver: String,
+    pub mail_from_name: String,
+    pub mail_from_address: String,
+    pub stock_encryption_keys: String,
+    pub stock_encryption_key_id: String,
+    pub reservation_ttl_secs: i64,
+    pub access_token_ttl_secs: i64,
+    pub refresh_token_ttl_secs: i64,
+    pub password_reset_url: String,
+    pub password_reset_ttl_secs: i64,
+    pub email_verification_url: String,
+    pub email_verification_ttl_secs: i64,
+    pub email_verification_cooldown_secs: i64,
+    pub two_factor_challenge_ttl_secs: i64,
+    pub login_max_failures: i64,
+    pub login_ip_max_failures: i64,
+    pub login_lockout_base_secs: i64,
+    pub login_lockout_max_secs: i64,
+    pub login_failure_window_secs: i64,
+    pub trusted_proxies: Vec<IpAddr>,
 }
 
 pub static CONFIG: Lazy<Config> = Lazy::new(|| {
@@ -20,6 +41,68 @@ pub static CONFIG: Lazy<Config> = Lazy::new(|| {
     let jwt_secret = env::var("JWT_SECRET").expect("JWT_SECRET가 설정되지 않았습니다.");
     let resend_api_key = env::var("RESEND_API_KEY").expect("RESEND_API_KEY가 설정되지 않았습니다.");
     let vronix_security_key = env::var("VRONIX_SECURITY_KEY").expect("VRONIX_SECURITY_KEY가 설정되지 않았습니다.");
+    let mail_driver = env::var("MAIL_DRIVER").unwrap_or_else(|_| "resend".to_string());
+    let mail_from_name = env::var("MAIL_FROM_NAME").unwrap_or_else(|_| "Acme".to_string());
+    let mail_from_address = env::var("MAIL_FROM_ADDRESS").unwrap_or_else(|_| "onboarding@resend.dev".to_string());
+    let stock_encryption_keys = env::var("STOCK_ENCRYPTION_KEYS").expect("STOCK_ENCRYPTION_KEYS가 설정되지 않았습니다.");
+    let stock_encryption_key_id = env::var("STOCK_ENCRYPTION_KEY_ID").expect("STOCK_ENCRYPTION_KEY_ID가 설정되지 않았습니다.");
+    let reservation_ttl_secs = env::var("RESERVATION_TTL_SECS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(15 * 60);
+    let access_token_ttl_secs = env::var("ACCESS_TOKEN_TTL_SECS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(15 * 60);
+    let refresh_token_ttl_secs = env::var("REFRESH_TOKEN_TTL_SECS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(60 * 60 * 24 * 30);
+    let password_reset_url = env::var("PASSWORD_RESET_URL").unwrap_or_else(|_| "http://localhost:3000/reset-password".to_string());
+    let password_reset_ttl_secs = env::var("PASSWORD_RESET_TTL_SECS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(60 * 60);
+    let email_verification_url = env::var("EMAIL_VERIFICATION_URL").unwrap_or_else(|_| "http://localhost:3000/verify-email".to_string());
+    let email_verification_ttl_secs = env::var("EMAIL_VERIFICATION_TTL_SECS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(60 * 60 * 24);
+    let email_verification_cooldown_secs = env::var("EMAIL_VERIFICATION_COOLDOWN_SECS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(60);
+    let two_factor_challenge_ttl_secs = env::var("TWO_FACTOR_CHALLENGE_TTL_SECS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(5 * 60);
+    let login_max_failures = env::var("LOGIN_MAX_FAILURES")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(5);
+    let login_ip_max_failures = env::var("LOGIN_IP_MAX_FAILURES")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(20);
+    let login_lockout_base_secs = env::var("LOGIN_LOCKOUT_BASE_SECS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(30);
+    let login_lockout_max_secs = env::var("LOGIN_LOCKOUT_MAX_SECS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(60 * 60);
+    let login_failure_window_secs = env::var("LOGIN_FAILURE_WINDOW_SECS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(60 * 60);
+    let trusted_proxies = env::var("TRUSTED_PROXIES")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| value.parse().expect("TRUSTED_PROXIES에 올바르지 않은 IP가 있습니다."))
+        .collect();
 
     Config {
         server_port,
@@ -28,5 +111,25 @@ pub static CONFIG: Lazy<Config> = Lazy::new(|| {
         jwt_secret,
         resend_api_key,
         vronix_security_key,
+        mail_driver,
+        mail_from_name,
+        mail_from_address,
+        stock_encryption_keys,
+        stock_encryption_key_id,
+        reservation_ttl_secs,
+        access_token_ttl_secs,
+        refresh_token_ttl_secs,
+        password_reset_url,
+        password_reset_ttl_secs,
+        email_verification_url,
+        email_verification_ttl_secs,
+        email_verification_cooldown_secs,
+        two_factor_challenge_ttl_secs,
+        login_max_failures,
+        login_ip_max_failures,
+        login_lockout_base_secs,
+        login_lockout_max_secs,
+        login_failure_window_secs,
+        trusted_proxies,
     }
 });
\ No newline at end of file
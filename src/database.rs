@@ -1,7 +1,15 @@
-use mongodb::{Client, Collection};
+use std::time::Duration;
+use bson::doc;
+use mongodb::{Client, Collection, IndexModel};
+use mongodb::options::IndexOptions;
 use mongodb::error::Result;
+use crate::auth::model::{ApiKey, LoginAttempt, PasswordResetToken, RefreshToken, RevokedToken, Session};
 use crate::config::CONFIG;
 use crate::category::model::Category;
+use crate::category::product::restock::model::RestockSubscription;
+use crate::order::model::Order;
+use crate::reservation::model::Reservation;
+use crate::role::model::Role;
 use crate::store::model::Store;
 use crate::user::model::User;
 
@@ -10,22 +18,201 @@ pub struct MongoRepository {
     pub user_collection: Collection<User>,
     pub store_collection: Collection<Store>,
     pub category_collection: Collection<Category>,
+    pub order_collection: Collection<Order>,
+    pub reservation_collection: Collection<Reservation>,
+    pub restock_subscription_collection: Collection<RestockSubscription>,
+    pub refresh_token_collection: Collection<RefreshToken>,
+    pub revoked_token_collection: Collection<RevokedToken>,
+    pub password_reset_collection: Collection<PasswordResetToken>,
+    pub login_attempt_collection: Collection<LoginAttempt>,
+    pub role_collection: Collection<Role>,
+    pub api_key_collection: Collection<ApiKey>,
+    pub session_collection: Collection<Session>,
 }
 
 impl MongoRepository {
     pub async fn init() -> Result<Self> {
+        let repo = MongoRepository::connect().await?;
+        repo.create_indexes().await?;
+
+        Ok(repo)
+    }
+
+    // 클라이언트는 첫 명령을 보낼 때 서버에 접속하므로 여기서는 컬렉션 핸들만 만듭니다.
+    pub async fn connect() -> Result<Self> {
         let client = Client::with_uri_str(&CONFIG.database_url).await?;
         let database = client.database(&CONFIG.database_name);
         let user_collection = database.collection::<User>("users");
         let store_collection = database.collection::<Store>("stores");
         let category_collection = database.collection::<Category>("categories");
+        let order_collection = database.collection::<Order>("orders");
+        let reservation_collection = database.collection::<Reservation>("reservations");
+        let restock_subscription_collection = database.collection::<RestockSubscription>("restock_subscriptions");
+        let refresh_token_collection = database.collection::<RefreshToken>("refresh_tokens");
+        let revoked_token_collection = database.collection::<RevokedToken>("revoked_tokens");
+        let password_reset_collection = database.collection::<PasswordResetToken>("password_reset_tokens");
+        let login_attempt_collection = database.collection::<LoginAttempt>("login_attempts");
+        let role_collection = database.collection::<Role>("roles");
+        let api_key_collection = database.collection::<ApiKey>("api_keys");
+        let session_collection = database.collection::<Session>("sessions");
+
+        let repo = MongoRepository {
+            user_collection,
+            store_collection,
+            category_collection,
+            order_collection,
+            reservation_collection,
+            restock_subscription_collection,
+            refresh_token_collection,
+            revoked_token_collection,
+            password_reset_collection,
+            login_attempt_collection,
+            role_collection,
+            api_key_collection,
+            session_collection,
+        };
+
+        Ok(repo)
+    }
+
+    async fn create_indexes(&self) -> Result<()> {
+        self.user_collection
+            .create_index(IndexModel::builder().keys(doc! { "store_id": 1, "_id": -1 }).build())
+            .await?;
+        self.user_collection
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "store_id": 1, "email": 1 })
+                    .options(IndexOptions::builder().unique(true).build())
+                    .build(),
+            )
+            .await?;
+        self.user_collection
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "store_id": 1, "user_id": 1 })
+                    .options(IndexOptions::builder().unique(true).build())
+                    .build(),
+            )
+            .await?;
+        self.order_collection
+            .create_index(IndexModel::builder().keys(doc! { "store_id": 1, "user_id": 1, "_id": -1 }).build())
+            .await?;
+        self.order_collection
+            .create_index(IndexModel::builder().keys(doc! { "store_id": 1, "_id": -1 }).build())
+            .await?;
+        self.reservation_collection
+            .create_index(IndexModel::builder().keys(doc! { "status": 1, "expires_at": 1 }).build())
+            .await?;
+        self.reservation_collection
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "release_retry_at": 1 })
+                    .options(IndexOptions::builder().sparse(true).build())
+                    .build(),
+            )
+            .await?;
+        self.restock_subscription_collection
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "store_id": 1, "category_name": 1, "product_name": 1, "user_id": 1 })
+                    .options(IndexOptions::builder().unique(true).build())
+                    .build(),
+            )
+            .await?;
+        self.refresh_token_collection
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "token_hash": 1 })
+                    .options(IndexOptions::builder().unique(true).build())
+                    .build(),
+            )
+            .await?;
+        self.refresh_token_collection
+            .create_index(IndexModel::builder().keys(doc! { "family_id": 1 }).build())
+            .await?;
+        self.refresh_token_collection
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "expires_at": 1 })
+                    .options(IndexOptions::builder().expire_after(Duration::from_secs(0)).build())
+                    .build(),
+            )
+            .await?;
+        self.revoked_token_collection
+            .create_index(IndexModel::builder().keys(doc! { "store_id": 1, "user_id": 1 }).build())
+            .await?;
+        self.revoked_token_collection
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "expires_at": 1 })
+                    .options(IndexOptions::builder().expire_after(Duration::from_secs(0)).build())
+                    .build(),
+            )
+            .await?;
+        self.password_reset_collection
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "token_hash": 1 })
+                    .options(IndexOptions::builder().unique(true).build())
+                    .build(),
+            )
+            .await?;
+        self.password_reset_collection
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "expires_at": 1 })
+                    .options(IndexOptions::builder().expire_after(Duration::from_secs(0)).build())
+                    .build(),
+            )
+            .await?;
+        self.login_attempt_collection
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "store_id": 1, "key": 1 })
+                    .options(IndexOptions::builder().unique(true).build())
+                    .build(),
+            )
+            .await?;
+        self.login_attempt_collection
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "expires_at": 1 })
+                    .options(IndexOptions::builder().expire_after(Duration::from_secs(0)).build())
+                    .build(),
+            )
+            .await?;
+        self.role_collection
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "store_id": 1, "name": 1 })
+                    .options(IndexOptions::builder().unique(true).build())
+                    .build(),
+            )
+            .await?;
+        self.api_key_collection
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "key_hash": 1 })
+                    .options(IndexOptions::builder().unique(true).build())
+                    .build(),
+            )
+            .await?;
+        self.api_key_collection
+            .create_index(IndexModel::builder().keys(doc! { "store_id": 1, "_id": -1 }).build())
+            .await?;
+        self.session_collection
+            .create_index(IndexModel::builder().keys(doc! { "store_id": 1, "user_id": 1, "last_seen_at": -1 }).build())
+            .await?;
+        self.session_collection
+            .create_index(
+                IndexModel::builder()
+                    .keys(doc! { "expires_at": 1 })
+                    .options(IndexOptions::builder().expire_after(Duration::from_secs(0)).build())
+                    .build(),
+            )
+            .await?;
 
-        Ok(
-            MongoRepository {
-                user_collection,
-                store_collection,
-                category_collection,
-            }
-        )
+        Ok(())
     }
 }
\ No newline at end of file
@@ -1,30 +1,51 @@
+mod app;
 mod database;
+mod auth;
 mod user;
 mod common;
 mod config;
 mod store;
 mod category;
+mod order;
+mod migration;
+mod reservation;
+mod role;
 
+use std::net::SocketAddr;
 use std::sync::Arc;
-use axum::{Extension, Router};
+use once_cell::sync::Lazy;
+use crate::common::crypto::STOCK_CIPHER;
+use crate::common::email::init_mailer;
 use crate::config::CONFIG;
 use crate::database::MongoRepository;
 
 #[tokio::main]
 async fn main() {
+    Lazy::force(&STOCK_CIPHER);
+
     let mongo_repo = MongoRepository::init().await.expect("MongoDB 초기화를 실패하였습니다.");
 
-    let app = Router::new()
-        .merge(user::handler::user_routes())
-        .merge(store::handler::store_routes())
-        .merge(category::handler::category_routes())
-        .merge(category::product::handler::product_routes())
-        .merge(category::product::stock::handler::stock_routes())
-        .layer(Extension(Arc::new(mongo_repo)));
+    if std::env::args().nth(1).as_deref() == Some("encrypt-stock") {
+        let report = migration::encrypt_stock(&mongo_repo).await.expect("재고 암호화 마이그레이션에 실패하였습니다.");
+        println!(
+            "재고 암호화 완료: 제품 {}개, 주문 {}개, 2단계 인증 시크릿 {}개 (건너뜀 {}개)",
+            report.products, report.orders, report.totp_secrets, report.skipped
+        );
+        return;
+    }
+
+    let mongo_repo = Arc::new(mongo_repo);
+    let mailer = init_mailer();
+
+    reservation::helper::spawn_reservation_sweeper(mongo_repo.clone(), mailer.clone());
+
+    let app = app::build_router(app::routes(), mongo_repo, mailer);
 
     let listener = tokio::net::TcpListener::bind(format!("127.0.0.1:{}", &CONFIG.server_port))
         .await
         .unwrap();
     println!("listening on {}", listener.local_addr().unwrap());
-    axum::serve(listener, app).await.unwrap();
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
+        .await
+        .unwrap();
 }
\ No newline at end of file
@@ -0,0 +1,95 @@
+use crate::common::crypto::STOCK_CIPHER;
+use crate::database::MongoRepository;
+
+#[derive(Debug, Default)]
+pub struct EncryptStockReport {
+    pub products: usize,
+    pub orders: usize,
+    pub totp_secrets: usize,
+    pub skipped: usize,
+}
+
+pub async fn encrypt_stock(mongo_repo: &MongoRepository) -> mongodb::error::Result<EncryptStockReport> {
+    let mut report = EncryptStockReport::default();
+
+    let mut categories = mongo_repo.find_all_categories().await?;
+    while categories.advance().await? {
+        let category = categories.deserialize_current()?;
+        let Some(category_id) = category.object_id else { continue };
+
+        for product in category.products {
+            let Some(product_id) = product.object_id else { continue };
+            if !product.stock.iter().any(|code| STOCK_CIPHER.needs_reencryption(code)) {
+                continue;
+            }
+
+            let stock = match STOCK_CIPHER.decrypt_all(&product.stock) {
+                Ok(stock) => STOCK_CIPHER.encrypt_all(&stock),
+                Err(err) => {
+                    println!("제품 '{}' 재고를 복호화하지 못했습니다: {}", product.name, err);
+                    report.skipped += 1;
+                    continue;
+                }
+            };
+
+            if mongo_repo.replace_product_stock(&category_id, &product_id, &product.stock, &stock).await? {
+                report.products += 1;
+            } else {
+                report.skipped += 1;
+            }
+        }
+    }
+
+    let mut orders = mongo_repo.find_all_orders().await?;
+    while orders.advance().await? {
+        let order = orders.deserialize_current()?;
+        let Some(order_id) = order.object_id else { continue };
+        if !order.codes.iter().any(|code| STOCK_CIPHER.needs_reencryption(code)) {
+            continue;
+        }
+
+        let codes = match STOCK_CIPHER.decrypt_all(&order.codes) {
+            Ok(codes) => STOCK_CIPHER.encrypt_all(&codes),
+            Err(err) => {
+                println!("주문 '{}' 코드를 복호화하지 못했습니다: {}", order_id, err);
+                report.skipped += 1;
+                continue;
+            }
+        };
+
+        if mongo_repo.replace_order_codes(&order_id, &order.codes, &codes).await? {
+            report.orders += 1;
+        } else {
+            report.skipped += 1;
+        }
+    }
+
+    let mut users = mongo_repo.find_users_with_totp_secret().await?;
+    while users.advance().await? {
+        let user = users.deserialize_current()?;
+        let Some(user_id) = user.object_id else { continue };
+
+        for (field, secret) in [("totp_secret", &user.totp_secret), ("totp_pending_secret", &user.totp_pending_secret)] {
+            let Some(secret) = secret.as_deref().filter(|secret| STOCK_CIPHER.secret_needs_reencryption(secret)) else {
+                continue;
+            };
+
+            let replacement = match STOCK_CIPHER.decrypt_secret(secret) {
+                Ok(plaintext) => STOCK_CIPHER.encrypt_secret(&plaintext),
+                Err(err) => {
+                    println!("유저 '{}' 2단계 인증 시크릿을 복호화하지 못했습니다: {}", user_id, err);
+                    report.skipped += 1;
+                    continue;
+                }
+            };
+
+            if mongo_repo.replace_totp_secret(&user_id, field, secret, &replacement).await? {
+                report.totp_secrets += 1;
+            } else {
+                report.skipped += 1;
+            }
+        }
+    }
+
+    Ok(report)
+}
@@ -0,0 +1,201 @@
+use std::sync::Arc;
+use axum::extract::{Path, Query};
+use axum::http::{Method, StatusCode};
+use axum::{Extension, Json, Router};
+use axum::response::{IntoResponse, Response};
+use crate::auth::policy::PolicyRouter;
+use bson::oid::ObjectId;
+use crate::auth::extractor::{AuthUser, Authorized};
+use crate::category::product::stock::helper::spawn_low_stock_check;
+use crate::common::crypto::STOCK_CIPHER;
+use crate::common::email::Mailer;
+use crate::common::error::AppError;
+use crate::common::id::parse_object_id;
+use crate::common::i18n::Message;
+use crate::common::pagination::{page_limit, parse_cursor, split_page};
+use crate::common::response::ErrorResponse;
+use crate::common::types::Status;
+use crate::database::MongoRepository;
+use crate::order::helper::{fulfil_order, send_order_receipt, take_stock_for};
+use crate::order::model::{
+    AdminOrderListQuery, Order, OrderDetail, OrderFilter, OrderListQuery, OrderListResponse, OrderResponse, PurchaseRequest,
+};
+
+pub fn order_routes() -> Router {
+    Router::new()
+        .policy_route(Method::POST, "/api/category/:category_name/product/:product_name/purchase", purchase_product)
+        .policy_route(Method::GET, "/api/orders", list_my_orders)
+        .policy_route(Method::GET, "/api/orders/:order_id", get_my_order)
+        .policy_route(Method::POST, "/api/orders/:order_id/receipt", resend_receipt)
+        .policy_route(Method::GET, "/api/admin/orders", list_store_orders)
+}
+
+pub async fn purchase_product(
+    AuthUser { store, user, .. }: AuthUser,
+    Path((category_name, product_name)): Path<(String, String)>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Extension(mailer): Extension<Arc<dyn Mailer>>,
+    Json(body): Json<PurchaseRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let (product, codes) =
+        take_stock_for(&mongo_repo, &store, &user, &category_name, &product_name, body.quantity).await?;
+    let store_id = store.object_id.unwrap();
+
+    let result = async {
+        let delivered_codes = STOCK_CIPHER.decrypt_all(&codes)?;
+
+        let order = Order::new(
+            store_id,
+            user.object_id.unwrap(),
+            category_name.clone(),
+            product.object_id,
+            product_name.clone(),
+            product.final_price(),
+            STOCK_CIPHER.encrypt_all(&delivered_codes),
+        );
+
+        Ok::<_, AppError>(fulfil_order(&mongo_repo, &mailer, &store, &user, order, delivered_codes).await?)
+    }
+    .await;
+
+    let order = match result {
+        Ok(order) => order,
+        Err(err) => {
+            match mongo_repo.push_product_stock(&store_id, &category_name, &product_name, &codes, None).await {
+                Ok(Some(_)) => {}
+                Ok(None) => println!(
+                    "주문 실패({}) 후 재고 {}개를 되돌릴 제품 '{}'를 찾을 수 없습니다.",
+                    err,
+                    codes.len(),
+                    product_name
+                ),
+                Err(rollback_err) => {
+                    println!(
+                        "주문 실패({}) 후 '{}' 재고 {}개 반환 실패: {}",
+                        err,
+                        product_name,
+                        codes.len(),
+                        rollback_err
+                    );
+                    return Err(rollback_err.into());
+                }
+            }
+            return Err(err);
+        }
+    };
+
+    spawn_low_stock_check(mongo_repo.clone(), mailer.clone(), store, category_name, product_name);
+
+    let response = OrderResponse {
+        status: Status::Success,
+        order,
+    };
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+pub async fn resend_receipt(
+    AuthUser { store, user, .. }: AuthUser,
+    Path(order_id): Path<String>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Extension(mailer): Extension<Arc<dyn Mailer>>,
+) -> Result<impl IntoResponse, AppError> {
+    let order_id = parse_object_id(&order_id, "INVALID_ORDER_ID")?;
+
+    let order = mongo_repo
+        .find_order_for_user(&store.object_id.unwrap(), &user.object_id.unwrap(), &order_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(Message::new("ORDER_NOT_FOUND")))?;
+    let order = OrderDetail::reveal(order)?;
+
+    send_order_receipt(&mailer, &store, &user.email, &order).await?;
+
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("RECEIPT_RESENT").localize(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn list_my_orders(
+    AuthUser { store, user, .. }: AuthUser,
+    Query(query): Query<OrderListQuery>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    let cursor = parse_cursor(&query.cursor)?;
+
+    let filter = OrderFilter {
+        user_id: user.object_id,
+        ..Default::default()
+    };
+
+    list_orders(&mongo_repo, &store.object_id.unwrap(), &filter, cursor, page_limit(query.limit)).await
+}
+
+pub async fn get_my_order(
+    AuthUser { store, user, .. }: AuthUser,
+    Path(order_id): Path<String>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    let order_id = parse_object_id(&order_id, "INVALID_ORDER_ID")?;
+
+    let order = mongo_repo
+        .find_order_for_user(&store.object_id.unwrap(), &user.object_id.unwrap(), &order_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(Message::new("ORDER_NOT_FOUND")))?;
+
+    let response = OrderResponse {
+        status: Status::Success,
+        order: OrderDetail::reveal(order)?,
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn list_store_orders(
+    Authorized { store, .. }: Authorized,
+    Query(query): Query<AdminOrderListQuery>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    let cursor = parse_cursor(&query.cursor)?;
+
+    let user_id = query
+        .user_id
+        .as_deref()
+        .map(|id| parse_object_id(id, "INVALID_USER_ID"))
+        .transpose()?;
+
+    let product_id = query
+        .product_id
+        .as_deref()
+        .map(|id| parse_object_id(id, "INVALID_PRODUCT_ID"))
+        .transpose()?;
+
+    let filter = OrderFilter {
+        user_id,
+        product_id,
+        product_name: query.product_name.clone(),
+        status: query.status.clone(),
+        from: query.from,
+        to: query.to,
+    };
+
+    list_orders(&mongo_repo, &store.object_id.unwrap(), &filter, cursor, page_limit(query.limit)).await
+}
+
+async fn list_orders(
+    mongo_repo: &Arc<MongoRepository>,
+    store_id: &ObjectId,
+    filter: &OrderFilter,
+    cursor: Option<ObjectId>,
+    limit: i64,
+) -> Result<Response, AppError> {
+    let orders = mongo_repo.find_orders(store_id, filter, cursor, limit + 1).await?;
+    let (orders, next_cursor) = split_page(orders, limit, |order| order.object_id);
+    let orders = orders.into_iter().map(OrderDetail::reveal).collect::<Result<Vec<_>, _>>()?;
+
+    let response = OrderListResponse {
+        status: Status::Success,
+        orders,
+        next_cursor,
+    };
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
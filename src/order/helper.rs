@@ -0,0 +1,208 @@
+use std::sync::Arc;
+use crate::category::product::helper::{find_product_in_category, get_category_summary_from_store};
+use crate::category::product::model::Product;
+use crate::common::email::{escape_html, send_email, MailError, Mailer};
+use crate::common::error::AppError;
+use crate::common::i18n::{requested_locale_or, Locale, Message};
+use crate::database::MongoRepository;
+use crate::order::model::{Order, OrderDetail};
+use crate::store::model::Store;
+use crate::user::helper::ensure_email_verified;
+use crate::user::model::User;
+
+pub fn render_receipt(store: &Store, order: &OrderDetail, locale: Locale) -> String {
+    let text = |code| Message::new(code).render(locale);
+    let codes = order
+        .codes
+        .iter()
+        .map(|code| format!("<li><code>{}</code></li>", escape_html(code)))
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        r#"<div style="font-family: sans-serif; max-width: 560px; margin: 0 auto;">
+  <h2>{title}</h2>
+  <p>{intro}</p>
+  <table style="width: 100%; border-collapse: collapse;">
+    <tr><td>{order_id_label}</td><td>{order_id}</td></tr>
+    <tr><td>{product_label}</td><td>{category} / {product}</td></tr>
+    <tr><td>{quantity_label}</td><td>{quantity}</td></tr>
+    <tr><td>{unit_price_label}</td><td>{unit_price:.2}</td></tr>
+    <tr><td>{total_price_label}</td><td>{total_price:.2}</td></tr>
+    <tr><td>{create_at_label}</td><td>{create_at}</td></tr>
+  </table>
+  <h3>{codes_label}</h3>
+  <ul>{codes}</ul>
+  <p style="color: #888; font-size: 12px;">{no_reply}</p>
+</div>"#,
+        title = Message::new("MAIL_RECEIPT_TITLE").arg(escape_html(&store.name)).render(locale),
+        intro = text("MAIL_RECEIPT_INTRO"),
+        order_id_label = text("MAIL_RECEIPT_ORDER_ID"),
+        order_id = order.id.clone().unwrap_or_default(),
+        product_label = text("MAIL_RECEIPT_PRODUCT"),
+        category = escape_html(&order.category_name),
+        product = escape_html(&order.product_name),
+        quantity_label = text("MAIL_RECEIPT_QUANTITY"),
+        quantity = order.quantity,
+        unit_price_label = text("MAIL_RECEIPT_UNIT_PRICE"),
+        unit_price = order.unit_price,
+        total_price_label = text("MAIL_RECEIPT_TOTAL_PRICE"),
+        total_price = order.total_price,
+        create_at_label = text("MAIL_RECEIPT_ORDERED_AT"),
+        create_at = order.create_at.format("%Y-%m-%d %H:%M:%S UTC"),
+        codes_label = text("MAIL_RECEIPT_CODES"),
+        codes = codes,
+        no_reply = text("MAIL_NO_REPLY"),
+    )
+}
+
+pub async fn send_order_receipt(
+    mailer: &Arc<dyn Mailer>,
+    store: &Store,
+    email: &str,
+    order: &OrderDetail,
+) -> Result<(), MailError> {
+    let locale = requested_locale_or(store.default_locale);
+    let subject = Message::new("MAIL_RECEIPT_SUBJECT")
+        .arg(&store.name)
+        .arg(&order.product_name)
+        .render(locale);
+    let body = render_receipt(store, order, locale);
+
+    send_email(mailer, &store.sender(), vec![email.to_string()], &subject, &body).await
+}
+
+// 구매와 예약이 공통으로 거치는 검증을 마친 뒤 재고에서 quantity 개의 코드를 꺼냅니다.
+pub async fn take_stock_for(
+    mongo_repo: &Arc<MongoRepository>,
+    store: &Store,
+    user: &User,
+    category_name: &str,
+    product_name: &str,
+    quantity: u32,
+) -> Result<(Product, Vec<String>), AppError> {
+    ensure_email_verified(store, user)?;
+
+    if quantity == 0 {
+        return Err(AppError::Validation(Message::new("INVALID_QUANTITY")));
+    }
+
+    let mut category = get_category_summary_from_store(&store.object_id, category_name, mongo_repo).await?;
+    let product = find_product_in_category(&mut category, product_name)?.clone();
+
+    let codes = mongo_repo
+        .take_product_stock(&store.object_id.unwrap(), category_name, product_name, quantity as usize)
+        .await?
+        .ok_or_else(|| AppError::Conflict(Message::new("OUT_OF_STOCK").arg(product_name)))?;
+
+    Ok((product, codes))
+}
+
+pub async fn fulfil_order(
+    mongo_repo: &Arc<MongoRepository>,
+    mailer: &Arc<dyn Mailer>,
+    store: &Store,
+    user: &User,
+    mut order: Order,
+    delivered_codes: Vec<String>,
+) -> mongodb::error::Result<OrderDetail> {
+    let order_id = mongo_repo.create_order(order.clone()).await?;
+    order.object_id = Some(order_id);
+    let order = OrderDetail::with_codes(order, delivered_codes);
+
+    if let Err(err) = send_order_receipt(mailer, store, &user.email, &order).await {
+        println!("영수증 메일 발송 실패 ({}): {}", order_id, err);
+    }
+
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bson::oid::ObjectId;
+    use crate::common::crypto::STOCK_CIPHER;
+    use crate::common::email::InMemoryMailer;
+    use crate::common::testing::init_config;
+
+    fn store() -> Store {
+        let mut store = Store::new("<Acme>".to_string());
+        store.sender_name = Some("Acme Shop".to_string());
+        store.sender_address = Some("shop@example.com".to_string());
+        store
+    }
+
+    fn order(codes: Vec<String>) -> Order {
+        let mut order = Order::new(
+            ObjectId::new(),
+            ObjectId::new(),
+            "games".to_string(),
+            Some(ObjectId::new()),
+            "Gift Card".to_string(),
+            12.5,
+            codes,
+        );
+        order.object_id = Some(ObjectId::new());
+        order
+    }
+
+    #[tokio::test]
+    async fn receipt_is_sent_to_the_buyer_with_codes() {
+        init_config();
+        let memory = Arc::new(InMemoryMailer::new());
+        let mailer: Arc<dyn Mailer> = memory.clone();
+        let codes = vec!["CODE-1".to_string(), "<CODE-2>".to_string()];
+        let order = OrderDetail::with_codes(order(Vec::new()), codes);
+
+        send_order_receipt(&mailer, &store(), "buyer@example.com", &order).await.unwrap();
+
+        let sent = memory.sent();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].from, "Acme Shop <shop@example.com>");
+        assert_eq!(sent[0].to, vec!["buyer@example.com".to_string()]);
+        assert!(sent[0].subject.contains("Gift Card"));
+        assert!(sent[0].html.contains("<code>CODE-1</code>"));
+        assert!(sent[0].html.contains("<code>&lt;CODE-2&gt;</code>"));
+        assert!(sent[0].html.contains("&lt;Acme&gt;"));
+        assert!(sent[0].html.contains(&order.id.clone().unwrap()));
+    }
+
+    #[tokio::test]
+    async fn receipt_follows_the_store_default_locale() {
+        init_config();
+        let memory = Arc::new(InMemoryMailer::new());
+        let mailer: Arc<dyn Mailer> = memory.clone();
+        let mut store = store();
+        store.default_locale = Locale::En;
+        let order = OrderDetail::with_codes(order(Vec::new()), vec!["CODE-1".to_string()]);
+
+        send_order_receipt(&mailer, &store, "buyer@example.com", &order).await.unwrap();
+
+        let sent = memory.sent();
+        assert_eq!(sent[0].subject, "[<Acme>] Receipt for 'Gift Card'");
+        assert!(sent[0].html.contains("Product codes"));
+        assert!(!sent[0].html.contains("상품 코드"));
+    }
+
+    #[tokio::test]
+    async fn resent_receipt_reveals_stored_codes() {
+        init_config();
+        let memory = Arc::new(InMemoryMailer::new());
+        let mailer: Arc<dyn Mailer> = memory.clone();
+        let codes = vec!["CODE-1".to_string(), "CODE-2".to_string()];
+        let stored = order(STOCK_CIPHER.encrypt_all(&codes));
+
+        for _ in 0..2 {
+            let order = OrderDetail::reveal(stored.clone()).unwrap();
+            send_order_receipt(&mailer, &store(), "buyer@example.com", &order).await.unwrap();
+        }
+
+        let sent = memory.sent();
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[0].html, sent[1].html);
+        for code in &codes {
+            assert!(sent[1].html.contains(&format!("<code>{}</code>", code)));
+        }
+        assert!(!sent[1].html.contains("enc:v2:"));
+    }
+}
@@ -0,0 +1,147 @@
+use bson::oid::ObjectId;
+use bson::serde_helpers::chrono_datetime_as_bson_datetime;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::common::crypto::{CryptoError, STOCK_CIPHER};
+use crate::common::types::Status;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum OrderStatus {
+    Completed,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Order {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<ObjectId>,
+    pub store_id: Option<ObjectId>,
+    pub user_id: Option<ObjectId>,
+    pub category_name: String,
+    pub product_id: Option<ObjectId>,
+    pub product_name: String,
+    pub quantity: u32,
+    pub unit_price: f64,
+    pub total_price: f64,
+    pub codes: Vec<String>,
+    pub status: OrderStatus,
+    #[serde(with = "chrono_datetime_as_bson_datetime")]
+    pub create_at: DateTime<Utc>,
+}
+
+impl Order {
+    pub fn new(
+        store_id: ObjectId,
+        user_id: ObjectId,
+        category_name: String,
+        product_id: Option<ObjectId>,
+        product_name: String,
+        unit_price: f64,
+        codes: Vec<String>,
+    ) -> Self {
+        let quantity = codes.len() as u32;
+        Order {
+            object_id: None,
+            store_id: Some(store_id),
+            user_id: Some(user_id),
+            category_name,
+            product_id,
+            product_name,
+            quantity,
+            unit_price,
+            total_price: unit_price * quantity as f64,
+            codes,
+            status: OrderStatus::Completed,
+            create_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OrderDetail {
+    pub id: Option<String>,
+    pub user_id: Option<String>,
+    pub product_id: Option<String>,
+    pub category_name: String,
+    pub product_name: String,
+    pub quantity: u32,
+    pub unit_price: f64,
+    pub total_price: f64,
+    pub codes: Vec<String>,
+    pub status: OrderStatus,
+    pub create_at: DateTime<Utc>,
+}
+
+impl OrderDetail {
+    pub fn reveal(order: Order) -> Result<Self, CryptoError> {
+        let codes = STOCK_CIPHER.decrypt_all(&order.codes)?;
+        Ok(OrderDetail::with_codes(order, codes))
+    }
+
+    pub fn with_codes(order: Order, codes: Vec<String>) -> Self {
+        OrderDetail {
+            id: order.object_id.map(|id| id.to_hex()),
+            user_id: order.user_id.map(|id| id.to_hex()),
+            product_id: order.product_id.map(|id| id.to_hex()),
+            category_name: order.category_name,
+            product_name: order.product_name,
+            quantity: order.quantity,
+            unit_price: order.unit_price,
+            total_price: order.total_price,
+            codes,
+            status: order.status,
+            create_at: order.create_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OrderResponse {
+    pub status: Status,
+    pub order: OrderDetail,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OrderListResponse {
+    pub status: Status,
+    pub orders: Vec<OrderDetail>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OrderListQuery {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AdminOrderListQuery {
+    pub user_id: Option<String>,
+    pub product_id: Option<String>,
+    pub product_name: Option<String>,
+    pub status: Option<OrderStatus>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct OrderFilter {
+    pub user_id: Option<ObjectId>,
+    pub product_id: Option<ObjectId>,
+    pub product_name: Option<String>,
+    pub status: Option<OrderStatus>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PurchaseRequest {
+    #[serde(default = "default_quantity")]
+    pub quantity: u32,
+}
+
+fn default_quantity() -> u32 {
+    1
+}
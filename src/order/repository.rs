@@ -0,0 +1,95 @@
+use mongodb::bson::{doc, to_bson, DateTime, Document};
+use mongodb::bson::oid::ObjectId;
+use mongodb::Cursor;
+use mongodb::error::Result;
+use crate::database::MongoRepository;
+use crate::order::model::{Order, OrderFilter};
+
+impl MongoRepository {
+    pub async fn create_order(&self, new_order: Order) -> Result<ObjectId> {
+        let order = self.order_collection.insert_one(new_order).await?;
+        Ok(order.inserted_id.as_object_id().unwrap())
+    }
+
+    pub async fn find_order_for_user(
+        &self,
+        store_id: &ObjectId,
+        user_id: &ObjectId,
+        order_id: &ObjectId,
+    ) -> Result<Option<Order>> {
+        let filter = doc! { "_id": order_id, "store_id": store_id, "user_id": user_id };
+        self.order_collection.find_one(filter).await
+    }
+
+    pub async fn find_orders(
+        &self,
+        store_id: &ObjectId,
+        filter: &OrderFilter,
+        cursor: Option<ObjectId>,
+        limit: i64,
+    ) -> Result<Vec<Order>> {
+        let mut query = doc! { "store_id": store_id };
+
+        if let Some(user_id) = filter.user_id {
+            query.insert("user_id", user_id);
+        }
+        if let Some(product_id) = filter.product_id {
+            query.insert("product_id", product_id);
+        }
+        if let Some(product_name) = &filter.product_name {
+            query.insert("product_name", product_name);
+        }
+        if let Some(status) = &filter.status {
+            query.insert("status", to_bson(status)?);
+        }
+        if filter.from.is_some() || filter.to.is_some() {
+            let mut range = Document::new();
+            if let Some(from) = filter.from {
+                range.insert("$gte", DateTime::from_chrono(from));
+            }
+            if let Some(to) = filter.to {
+                range.insert("$lt", DateTime::from_chrono(to));
+            }
+            query.insert("create_at", range);
+        }
+        if let Some(cursor) = cursor {
+            query.insert("_id", doc! { "$lt": cursor });
+        }
+
+        let mut cursor = self
+            .order_collection
+            .find(query)
+            .sort(doc! { "_id": -1 })
+            .limit(limit)
+            .await?;
+
+        let mut orders = vec![];
+        while cursor.advance().await? {
+            orders.push(cursor.deserialize_current()?);
+        }
+        Ok(orders)
+    }
+
+    pub async fn find_all_orders(&self) -> Result<Cursor<Order>> {
+        self.order_collection.find(doc! {}).await
+    }
+
+    pub async fn replace_order_codes(
+        &self,
+        order_id: &ObjectId,
+        current: &[String],
+        replacement: &[String],
+    ) -> Result<bool> {
+        let filter = doc! { "_id": order_id, "codes": current };
+        let update = doc! { "$set": { "codes": replacement } };
+        let result = self.order_collection.update_one(filter, update).await?;
+        Ok(result.modified_count > 0)
+    }
+
+    pub async fn anonymize_user_orders(&self, store_id: &ObjectId, user_id: &ObjectId) -> Result<u64> {
+        let filter = doc! { "store_id": store_id, "user_id": user_id };
+        let update = doc! { "$set": { "user_id": null } };
+        let result = self.order_collection.update_many(filter, update).await?;
+        Ok(result.modified_count)
+    }
+}
@@ -0,0 +1,172 @@
+use std::sync::Arc;
+use axum::extract::Path;
+use axum::http::{Method, StatusCode};
+use axum::{Extension, Json, Router};
+use axum::response::IntoResponse;
+use crate::auth::policy::PolicyRouter;
+use crate::auth::extractor::AuthUser;
+use crate::category::product::stock::helper::spawn_low_stock_check;
+use crate::common::crypto::STOCK_CIPHER;
+use crate::common::email::Mailer;
+use crate::common::error::AppError;
+use crate::common::id::parse_object_id;
+use crate::common::i18n::Message;
+use crate::common::types::Status;
+use crate::database::MongoRepository;
+use crate::order::helper::{fulfil_order, take_stock_for};
+use crate::order::model::{Order, OrderResponse, PurchaseRequest};
+use crate::reservation::helper::release_reservation;
+use crate::reservation::model::{Reservation, ReservationResponse, ReservationStatus};
+
+pub fn reservation_routes() -> Router {
+    Router::new()
+        .policy_route(Method::POST, "/api/category/:category_name/product/:product_name/reserve", reserve_product)
+        .policy_route(Method::POST, "/api/reservations/:reservation_id/confirm", confirm_reservation)
+        .policy_route(Method::POST, "/api/reservations/:reservation_id/cancel", cancel_reservation)
+}
+
+pub async fn reserve_product(
+    AuthUser { store, user, .. }: AuthUser,
+    Path((category_name, product_name)): Path<(String, String)>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Extension(mailer): Extension<Arc<dyn Mailer>>,
+    Json(body): Json<PurchaseRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let (product, codes) =
+        take_stock_for(&mongo_repo, &store, &user, &category_name, &product_name, body.quantity).await?;
+    let store_id = store.object_id.unwrap();
+
+    let mut reservation = Reservation::new(
+        store_id,
+        user.object_id.unwrap(),
+        category_name.clone(),
+        product.object_id,
+        product_name.clone(),
+        product.final_price(),
+        codes,
+    );
+
+    match mongo_repo.create_reservation(reservation.clone()).await {
+        Ok(reservation_id) => reservation.object_id = Some(reservation_id),
+        Err(err) => {
+            if let Err(rollback_err) = release_reservation(&mongo_repo, &reservation).await {
+                println!(
+                    "예약 생성 실패({}) 후 '{}' 재고 {}개 반환 실패: {}",
+                    err,
+                    product_name,
+                    reservation.codes.len(),
+                    rollback_err
+                );
+                return Err(rollback_err.into());
+            }
+            return Err(err.into());
+        }
+    }
+
+    spawn_low_stock_check(mongo_repo.clone(), mailer, store, category_name, product_name);
+
+    let response = ReservationResponse {
+        status: Status::Success,
+        reservation: reservation.into(),
+    };
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+pub async fn confirm_reservation(
+    AuthUser { store, user, .. }: AuthUser,
+    Path(reservation_id): Path<String>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Extension(mailer): Extension<Arc<dyn Mailer>>,
+) -> Result<impl IntoResponse, AppError> {
+    let reservation_id = parse_object_id(&reservation_id, "INVALID_RESERVATION_ID")?;
+
+    let reservation = mongo_repo
+        .close_pending_reservation(
+            &store.object_id.unwrap(),
+            &user.object_id.unwrap(),
+            &reservation_id,
+            ReservationStatus::Confirmed,
+        )
+        .await?
+        .ok_or_else(reservation_not_found)?;
+
+    let result = async {
+        let delivered_codes = STOCK_CIPHER.decrypt_all(&reservation.codes)?;
+
+        let order = Order::new(
+            store.object_id.unwrap(),
+            user.object_id.unwrap(),
+            reservation.category_name.clone(),
+            reservation.product_id,
+            reservation.product_name.clone(),
+            reservation.unit_price,
+            STOCK_CIPHER.encrypt_all(&delivered_codes),
+        );
+
+        Ok::<_, AppError>(fulfil_order(&mongo_repo, &mailer, &store, &user, order, delivered_codes).await?)
+    }
+    .await;
+
+    let order = match result {
+        Ok(order) => order,
+        Err(err) => {
+            if let Err(rollback_err) = mongo_repo.reopen_reservation(&reservation_id).await {
+                println!("예약 {} 확정 실패({}) 후 되돌리기 실패: {}", reservation_id, err, rollback_err);
+                return Err(rollback_err.into());
+            }
+            return Err(err);
+        }
+    };
+
+    if let Some(order_id) = order.id.as_deref().and_then(|id| id.parse().ok()) {
+        if let Err(err) = mongo_repo.set_reservation_order(&reservation_id, &order_id).await {
+            println!("예약 {}에 주문 {} 연결 실패: {}", reservation_id, order_id, err);
+        }
+    }
+
+    let response = OrderResponse {
+        status: Status::Success,
+        order,
+    };
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+pub async fn cancel_reservation(
+    AuthUser { store, user, .. }: AuthUser,
+    Path(reservation_id): Path<String>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Extension(mailer): Extension<Arc<dyn Mailer>>,
+) -> Result<impl IntoResponse, AppError> {
+    let reservation_id = parse_object_id(&reservation_id, "INVALID_RESERVATION_ID")?;
+
+    let reservation = mongo_repo
+        .close_pending_reservation(
+            &store.object_id.unwrap(),
+            &user.object_id.unwrap(),
+            &reservation_id,
+            ReservationStatus::Cancelled,
+        )
+        .await?
+        .ok_or_else(reservation_not_found)?;
+
+    match release_reservation(&mongo_repo, &reservation).await {
+        Ok(_) => spawn_low_stock_check(
+            mongo_repo.clone(),
+            mailer,
+            store,
+            reservation.category_name.clone(),
+            reservation.product_name.clone(),
+        ),
+        Err(err) => println!("취소된 예약 {}의 재고 반환 실패, 다음 정리 때 다시 시도합니다: {}", reservation_id, err),
+    }
+
+    let response = ReservationResponse {
+        status: Status::Success,
+        reservation: reservation.into(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+fn reservation_not_found() -> AppError {
+    AppError::NotFound(Message::new("RESERVATION_NOT_FOUND"))
+}
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use crate::category::product::stock::helper::spawn_low_stock_check;
+use crate::common::email::Mailer;
+use crate::database::MongoRepository;
+use crate::reservation::model::Reservation;
+
+const SWEEP_INTERVAL: Duration = Duration::from_secs(30);
+
+// 돌려놓기가 재시도될 수 있으므로 이미 들어간 코드는 다시 넣지 않도록 $addToSet 으로 넣습니다.
+pub async fn release_reservation(
+    mongo_repo: &Arc<MongoRepository>,
+    reservation: &Reservation,
+) -> mongodb::error::Result<()> {
+    let result = mongo_repo
+        .push_unique_product_stock(
+            &reservation.store_id.unwrap(),
+            &reservation.category_name,
+            &reservation.product_name,
+            &reservation.codes,
+            None,
+        )
+        .await?;
+
+    if result.is_none() {
+        println!(
+            "예약 {:?}의 재고를 되돌릴 제품 '{}'를 찾을 수 없습니다.",
+            reservation.object_id, reservation.product_name
+        );
+    }
+
+    if let Some(reservation_id) = reservation.object_id {
+        mongo_repo.mark_reservation_released(&reservation_id).await?;
+    }
+
+    Ok(())
+}
+
+pub fn spawn_reservation_sweeper(mongo_repo: Arc<MongoRepository>, mailer: Arc<dyn Mailer>) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(SWEEP_INTERVAL);
+        loop {
+            interval.tick().await;
+
+            loop {
+                match mongo_repo.expire_next_reservation().await {
+                    Ok(Some(reservation)) => release_and_check(&mongo_repo, &mailer, reservation).await,
+                    Ok(None) => break,
+                    Err(err) => {
+                        println!("만료된 예약 조회 실패: {}", err);
+                        break;
+                    }
+                }
+            }
+
+            // 재고 반환에 실패했던 예약은 lease 가 지난 뒤 다시 돌려놓습니다.
+            loop {
+                match mongo_repo.claim_unreleased_reservation().await {
+                    Ok(Some(reservation)) => release_and_check(&mongo_repo, &mailer, reservation).await,
+                    Ok(None) => break,
+                    Err(err) => {
+                        println!("재고 반환 대기 예약 조회 실패: {}", err);
+                        break;
+                    }
+                }
+            }
+        }
+    })
+}
+
+async fn release_and_check(mongo_repo: &Arc<MongoRepository>, mailer: &Arc<dyn Mailer>, reservation: Reservation) {
+    if let Err(err) = release_reservation(mongo_repo, &reservation).await {
+        println!("예약 {:?}의 재고 반환 실패, 다음 정리 때 다시 시도합니다: {}", reservation.object_id, err);
+        return;
+    }
+
+    if let Ok(Some(store)) = mongo_repo.find_store_by_id(&reservation.store_id.unwrap()).await {
+        spawn_low_stock_check(
+            mongo_repo.clone(),
+            mailer.clone(),
+            store,
+            reservation.category_name,
+            reservation.product_name,
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use bson::oid::ObjectId;
+use bson::serde_helpers::{chrono_datetime_as_bson_datetime, chrono_datetime_as_bson_datetime_optional};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use crate::common::types::Status;
+use crate::config::CONFIG;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ReservationStatus {
+    Pending,
+    Confirmed,
+    Cancelled,
+    Expired,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Reservation {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<ObjectId>,
+    pub store_id: Option<ObjectId>,
+    pub user_id: Option<ObjectId>,
+    pub category_name: String,
+    pub product_id: Option<ObjectId>,
+    pub product_name: String,
+    pub unit_price: f64,
+    pub codes: Vec<String>,
+    pub status: ReservationStatus,
+    pub order_id: Option<ObjectId>,
+    // 취소되거나 만료된 예약의 재고가 아직 돌아가지 않았으면 이 시각 이후 스위퍼가 다시 돌려놓습니다.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "chrono_datetime_as_bson_datetime_optional")]
+    pub release_retry_at: Option<DateTime<Utc>>,
+    #[serde(with = "chrono_datetime_as_bson_datetime")]
+    pub expires_at: DateTime<Utc>,
+    #[serde(with = "chrono_datetime_as_bson_datetime")]
+    pub create_at: DateTime<Utc>,
+}
+
+impl Reservation {
+    pub fn new(
+        store_id: ObjectId,
+        user_id: ObjectId,
+        category_name: String,
+        product_id: Option<ObjectId>,
+        product_name: String,
+        unit_price: f64,
+        codes: Vec<String>,
+    ) -> Self {
+        let now = Utc::now();
+        Reservation {
+            object_id: None,
+            store_id: Some(store_id),
+            user_id: Some(user_id),
+            category_name,
+            product_id,
+            product_name,
+            unit_price,
+            codes,
+            status: ReservationStatus::Pending,
+            order_id: None,
+            release_retry_at: None,
+            expires_at: now + Duration::seconds(CONFIG.reservation_ttl_secs),
+            create_at: now,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReservationDetail {
+    pub id: Option<String>,
+    pub category_name: String,
+    pub product_name: String,
+    pub quantity: usize,
+    pub unit_price: f64,
+    pub total_price: f64,
+    pub status: ReservationStatus,
+    pub order_id: Option<String>,
+    pub expires_at: DateTime<Utc>,
+    pub create_at: DateTime<Utc>,
+}
+
+impl From<Reservation> for ReservationDetail {
+    fn from(reservation: Reservation) -> Self {
+        let quantity = reservation.codes.len();
+        ReservationDetail {
+            id: reservation.object_id.map(|id| id.to_hex()),
+            category_name: reservation.category_name,
+            product_name: reservation.product_name,
+            quantity,
+            unit_price: reservation.unit_price,
+            total_price: reservation.unit_price * quantity as f64,
+            status: reservation.status,
+            order_id: reservation.order_id.map(|id| id.to_hex()),
+            expires_at: reservation.expires_at,
+            create_at: reservation.create_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReservationResponse {
+    pub status: Status,
+    pub reservation: ReservationDetail,
+}
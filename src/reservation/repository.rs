@@ -0,0 +1,116 @@
+use chrono::{Duration, Utc};
+use mongodb::bson::{doc, to_bson, DateTime};
+use mongodb::bson::oid::ObjectId;
+use mongodb::error::Result;
+use mongodb::options::ReturnDocument;
+use crate::database::MongoRepository;
+use crate::reservation::model::{Reservation, ReservationStatus};
+
+// 예약을 닫은 쪽이 재고를 돌려놓을 시간입니다. 이 시간이 지나도 돌아가지 않았으면 스위퍼가 이어받습니다.
+const STOCK_RELEASE_LEASE_SECS: i64 = 300;
+
+fn release_retry_at() -> DateTime {
+    DateTime::from_chrono(Utc::now() + Duration::seconds(STOCK_RELEASE_LEASE_SECS))
+}
+
+impl MongoRepository {
+    pub async fn create_reservation(&self, new_reservation: Reservation) -> Result<ObjectId> {
+        let reservation = self.reservation_collection.insert_one(new_reservation).await?;
+        Ok(reservation.inserted_id.as_object_id().unwrap())
+    }
+
+    pub async fn close_pending_reservation(
+        &self,
+        store_id: &ObjectId,
+        user_id: &ObjectId,
+        reservation_id: &ObjectId,
+        status: ReservationStatus,
+    ) -> Result<Option<Reservation>> {
+        let filter = doc! {
+            "_id": reservation_id,
+            "store_id": store_id,
+            "user_id": user_id,
+            "status": to_bson(&ReservationStatus::Pending)?,
+            "expires_at": { "$gt": DateTime::from_chrono(Utc::now()) },
+        };
+        let update = if status == ReservationStatus::Confirmed {
+            doc! { "$set": { "status": to_bson(&status)? } }
+        } else {
+            doc! { "$set": { "status": to_bson(&status)?, "release_retry_at": release_retry_at() } }
+        };
+
+        self.reservation_collection
+            .find_one_and_update(filter, update)
+            .return_document(ReturnDocument::After)
+            .await
+    }
+
+    pub async fn reopen_reservation(&self, reservation_id: &ObjectId) -> Result<()> {
+        let filter = doc! { "_id": reservation_id, "status": to_bson(&ReservationStatus::Confirmed)? };
+        let update = doc! { "$set": { "status": to_bson(&ReservationStatus::Pending)? } };
+        self.reservation_collection.update_one(filter, update).await?;
+        Ok(())
+    }
+
+    pub async fn set_reservation_order(&self, reservation_id: &ObjectId, order_id: &ObjectId) -> Result<()> {
+        let filter = doc! { "_id": reservation_id };
+        let update = doc! { "$set": { "order_id": order_id } };
+        self.reservation_collection.update_one(filter, update).await?;
+        Ok(())
+    }
+
+    pub async fn expire_next_reservation(&self) -> Result<Option<Reservation>> {
+        let filter = doc! {
+            "status": to_bson(&ReservationStatus::Pending)?,
+            "expires_at": { "$lte": DateTime::from_chrono(Utc::now()) },
+        };
+        let update = doc! {
+            "$set": { "status": to_bson(&ReservationStatus::Expired)?, "release_retry_at": release_retry_at() },
+        };
+
+        self.reservation_collection
+            .find_one_and_update(filter, update)
+            .return_document(ReturnDocument::After)
+            .await
+    }
+
+    pub async fn claim_unreleased_reservation(&self) -> Result<Option<Reservation>> {
+        let filter = doc! { "release_retry_at": { "$lte": DateTime::from_chrono(Utc::now()) } };
+        let update = doc! { "$set": { "release_retry_at": release_retry_at() } };
+
+        self.reservation_collection
+            .find_one_and_update(filter, update)
+            .return_document(ReturnDocument::After)
+            .await
+    }
+
+    pub async fn mark_reservation_released(&self, reservation_id: &ObjectId) -> Result<()> {
+        let filter = doc! { "_id": reservation_id };
+        let update = doc! { "$unset": { "release_retry_at": "" } };
+        self.reservation_collection.update_one(filter, update).await?;
+        Ok(())
+    }
+
+    pub async fn cancel_next_user_reservation(&self, store_id: &ObjectId, user_id: &ObjectId) -> Result<Option<Reservation>> {
+        let filter = doc! {
+            "store_id": store_id,
+            "user_id": user_id,
+            "status": to_bson(&ReservationStatus::Pending)?,
+        };
+        let update = doc! {
+            "$set": { "status": to_bson(&ReservationStatus::Cancelled)?, "release_retry_at": release_retry_at() },
+        };
+
+        self.reservation_collection
+            .find_one_and_update(filter, update)
+            .return_document(ReturnDocument::After)
+            .await
+    }
+
+    pub async fn anonymize_user_reservations(&self, store_id: &ObjectId, user_id: &ObjectId) -> Result<()> {
+        let filter = doc! { "store_id": store_id, "user_id": user_id };
+        let update = doc! { "$set": { "user_id": null } };
+        self.reservation_collection.update_many(filter, update).await?;
+        Ok(())
+    }
+}
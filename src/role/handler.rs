@@ -0,0 +1,129 @@
+use std::sync::Arc;
+use axum::{Extension, Json, Router};
+use axum::extract::Path;
+use axum::http::{Method, StatusCode};
+use axum::response::IntoResponse;
+use crate::auth::policy::PolicyRouter;
+use crate::auth::extractor::Authorized;
+use crate::common::error::AppError;
+use crate::common::i18n::Message;
+use crate::common::response::ErrorResponse;
+use crate::common::types::Status;
+use crate::database::MongoRepository;
+use crate::role::helper::{ensure_can_grant, validate_role_name};
+use crate::role::model::{
+    CreateRoleRequest, Permission, PublicRole, Role, RoleListResponse, RoleResponse, UpdateRoleRequest, BUILTIN_ROLES,
+};
+
+pub fn role_routes() -> Router {
+    Router::new()
+        .policy_route(Method::GET, "/api/admin/roles", list_roles)
+        .policy_route(Method::POST, "/api/admin/roles", create_role)
+        .policy_route(Method::PATCH, "/api/admin/roles/:role_name", update_role)
+        .policy_route(Method::DELETE, "/api/admin/roles/:role_name", delete_role)
+}
+
+pub async fn list_roles(
+    Authorized { store, .. }: Authorized,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    let custom_roles = mongo_repo.find_roles(&store.object_id.unwrap()).await?;
+    let roles = BUILTIN_ROLES
+        .iter()
+        .map(PublicRole::from)
+        .chain(custom_roles.iter().map(PublicRole::from))
+        .collect();
+
+    let response = RoleListResponse {
+        status: Status::Success,
+        roles,
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn create_role(
+    Authorized { store, permissions: granter, .. }: Authorized,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Json(body): Json<CreateRoleRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let store_id = store.object_id.unwrap();
+
+    validate_role_name(&body.name)?;
+    let permissions = normalize_permissions(body.permissions);
+    ensure_can_grant(&granter, &permissions)?;
+
+    if mongo_repo.find_role_by_name(&store_id, &body.name).await?.is_some() {
+        return Err(AppError::Conflict(Message::new("ROLE_NAME_TAKEN")));
+    }
+
+    let role = Role::new(store_id, body.name, permissions);
+    mongo_repo.create_role(role.clone()).await?;
+
+    let response = RoleResponse {
+        status: Status::Success,
+        role: (&role).into(),
+    };
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+pub async fn update_role(
+    Authorized { store, permissions: granter, .. }: Authorized,
+    Path(role_name): Path<String>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Json(body): Json<UpdateRoleRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let store_id = store.object_id.unwrap();
+
+    let current = mongo_repo
+        .find_role_by_name(&store_id, &role_name)
+        .await?
+        .ok_or_else(|| role_not_found(&role_name))?;
+    let permissions = normalize_permissions(body.permissions);
+    ensure_can_grant(&granter, current.permissions.iter().chain(&permissions))?;
+
+    let role = mongo_repo
+        .update_role_permissions(&store_id, &role_name, &permissions)
+        .await?
+        .ok_or_else(|| role_not_found(&role_name))?;
+
+    let response = RoleResponse {
+        status: Status::Success,
+        role: (&role).into(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn delete_role(
+    Authorized { store, permissions: granter, .. }: Authorized,
+    Path(role_name): Path<String>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    let store_id = store.object_id.unwrap();
+
+    let role = mongo_repo
+        .find_role_by_name(&store_id, &role_name)
+        .await?
+        .ok_or_else(|| role_not_found(&role_name))?;
+    ensure_can_grant(&granter, &role.permissions)?;
+
+    if !mongo_repo.delete_role(&store_id, &role_name).await? {
+        return Err(role_not_found(&role_name));
+    }
+    mongo_repo.remove_role_from_users(&store_id, &role_name).await?;
+
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("ROLE_DELETED").arg(role_name).localize(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+fn normalize_permissions(mut permissions: Vec<Permission>) -> Vec<Permission> {
+    permissions.sort();
+    permissions.dedup();
+    permissions
+}
+
+pub fn role_not_found(role_name: &str) -> AppError {
+    AppError::NotFound(Message::new("ROLE_NOT_FOUND").arg(role_name))
+}
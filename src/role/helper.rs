@@ -0,0 +1,77 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use bson::oid::ObjectId;
+use crate::auth::extractor::{Authorized, Caller};
+use crate::common::error::AppError;
+use crate::common::i18n::Message;
+use crate::database::MongoRepository;
+use crate::role::model::{Permission, BUILTIN_ROLES};
+use crate::user::model::User;
+
+const MAX_ROLE_NAME_LENGTH: usize = 32;
+
+pub async fn effective_permissions(
+    mongo_repo: &Arc<MongoRepository>,
+    store_id: &ObjectId,
+    user: &User,
+) -> Result<BTreeSet<Permission>, AppError> {
+    let mut permissions: BTreeSet<Permission> = user.rank.permissions().iter().copied().collect();
+
+    if !user.roles.is_empty() {
+        for role in mongo_repo.find_roles_by_names(store_id, &user.roles).await? {
+            permissions.extend(role.permissions);
+        }
+    }
+
+    Ok(permissions)
+}
+
+// 요청자의 권한만으로 판정하므로 DB 를 조회하지 않습니다.
+pub fn require_permission(caller: Caller, permission: Permission) -> Result<Authorized, AppError> {
+    // 짧게 발급한 키로 만료 없는 키를 새로 만드는 일을 막기 위해 키 관리는 로그인한 유저만 할 수 있습니다.
+    if caller.via_api_key && permission == Permission::ApiKeysManage {
+        return Err(AppError::Forbidden(Message::new("API_KEY_NOT_ALLOWED")));
+    }
+
+    if !caller.permissions.contains(&permission) {
+        return Err(permission_denied(permission));
+    }
+
+    // 관리 권한을 가진 계정은 등급과 관계없이 스토어의 관리자 2단계 인증 요구를 따릅니다.
+    if !caller.via_api_key && caller.store.require_admin_2fa && !caller.user.totp_enabled {
+        return Err(AppError::Forbidden(Message::new("ADMIN_TWO_FACTOR_REQUIRED")));
+    }
+
+    let Caller { store, user, permissions, .. } = caller;
+    Ok(Authorized { store, user, permissions })
+}
+
+// 자신이 가진 권한만 다른 유저나 역할에 부여할 수 있도록 해 권한 상승을 막습니다.
+pub fn ensure_can_grant<'a>(
+    granter: &BTreeSet<Permission>,
+    permissions: impl IntoIterator<Item = &'a Permission>,
+) -> Result<(), AppError> {
+    match permissions.into_iter().find(|permission| !granter.contains(permission)) {
+        Some(permission) => Err(permission_denied(*permission)),
+        None => Ok(()),
+    }
+}
+
+pub fn validate_role_name(name: &str) -> Result<(), AppError> {
+    let valid = !name.is_empty()
+        && name.len() <= MAX_ROLE_NAME_LENGTH
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        return Err(AppError::Validation(Message::new("INVALID_ROLE_NAME")));
+    }
+
+    if BUILTIN_ROLES.iter().any(|rank| rank.role_name().eq_ignore_ascii_case(name)) {
+        return Err(AppError::Conflict(Message::new("ROLE_NAME_TAKEN")));
+    }
+
+    Ok(())
+}
+
+pub fn permission_denied(permission: Permission) -> AppError {
+    AppError::Forbidden(Message::new("PERMISSION_DENIED").arg(permission.name()))
+}
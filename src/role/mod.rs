@@ -0,0 +1,4 @@
+pub mod model;
+pub mod repository;
+pub mod handler;
+pub mod helper;
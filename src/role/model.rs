@@ -0,0 +1,188 @@
+use bson::oid::ObjectId;
+use bson::serde_helpers::chrono_datetime_as_bson_datetime;
+use chrono::{DateTime, Utc};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use crate::common::types::Status;
+use crate::user::model::Rank;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Permission {
+    CategoryWrite,
+    CategoryDelete,
+    ProductWrite,
+    ProductDelete,
+    StockRead,
+    StockWrite,
+    OrdersRead,
+    UsersRead,
+    UsersManage,
+    RolesManage,
+    ApiKeysManage,
+}
+
+impl Permission {
+    pub const ALL: [Permission; 11] = [
+        Permission::CategoryWrite,
+        Permission::CategoryDelete,
+        Permission::ProductWrite,
+        Permission::ProductDelete,
+        Permission::StockRead,
+        Permission::StockWrite,
+        Permission::OrdersRead,
+        Permission::UsersRead,
+        Permission::UsersManage,
+        Permission::RolesManage,
+        Permission::ApiKeysManage,
+    ];
+
+    // 요청, 응답, DB 에 쓰이는 이름은 모두 여기서 나옵니다.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Permission::CategoryWrite => "category:write",
+            Permission::CategoryDelete => "category:delete",
+            Permission::ProductWrite => "product:write",
+            Permission::ProductDelete => "product:delete",
+            Permission::StockRead => "stock:read",
+            Permission::StockWrite => "stock:write",
+            Permission::OrdersRead => "orders:read",
+            Permission::UsersRead => "users:read",
+            Permission::UsersManage => "users:manage",
+            Permission::RolesManage => "roles:manage",
+            Permission::ApiKeysManage => "api-keys:manage",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Permission> {
+        Permission::ALL.into_iter().find(|permission| permission.name() == name)
+    }
+}
+
+impl Serialize for Permission {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Permission {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Permission::from_name(&name).ok_or_else(|| de::Error::custom(format!("알 수 없는 권한입니다: {}", name)))
+    }
+}
+
+// 기존 등급은 기본 역할로 취급합니다. 관리자는 모든 권한을 가지고 나머지 등급은 관리 권한이 없습니다.
+impl Rank {
+    pub fn role_name(&self) -> &'static str {
+        match self {
+            Rank::Customer => "customer",
+            Rank::Vip => "vip",
+            Rank::Administrator => "administrator",
+        }
+    }
+
+    pub fn permissions(&self) -> &'static [Permission] {
+        match self {
+            Rank::Customer | Rank::Vip => &[],
+            Rank::Administrator => &Permission::ALL,
+        }
+    }
+}
+
+pub const BUILTIN_ROLES: [Rank; 3] = [Rank::Customer, Rank::Vip, Rank::Administrator];
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Role {
+    #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
+    pub object_id: Option<ObjectId>,
+    pub store_id: Option<ObjectId>,
+    pub name: String,
+    pub permissions: Vec<Permission>,
+    #[serde(with = "chrono_datetime_as_bson_datetime")]
+    pub create_at: DateTime<Utc>,
+    #[serde(with = "chrono_datetime_as_bson_datetime")]
+    pub update_at: DateTime<Utc>,
+}
+
+impl Role {
+    pub fn new(store_id: ObjectId, name: String, permissions: Vec<Permission>) -> Self {
+        let now = Utc::now();
+        Role {
+            object_id: None,
+            store_id: Some(store_id),
+            name,
+            permissions,
+            create_at: now,
+            update_at: now,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreateRoleRequest {
+    pub name: String,
+    pub permissions: Vec<Permission>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UpdateRoleRequest {
+    pub permissions: Vec<Permission>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UpdateUserRolesRequest {
+    pub roles: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PublicRole {
+    pub name: String,
+    pub permissions: Vec<Permission>,
+    pub builtin: bool,
+}
+
+impl From<&Role> for PublicRole {
+    fn from(role: &Role) -> Self {
+        PublicRole {
+            name: role.name.clone(),
+            permissions: role.permissions.clone(),
+            builtin: false,
+        }
+    }
+}
+
+impl From<&Rank> for PublicRole {
+    fn from(rank: &Rank) -> Self {
+        PublicRole {
+            name: rank.role_name().to_string(),
+            permissions: rank.permissions().to_vec(),
+            builtin: true,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RoleResponse {
+    pub status: Status,
+    pub role: PublicRole,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RoleListResponse {
+    pub status: Status,
+    pub roles: Vec<PublicRole>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn permissions_round_trip_through_their_names() {
+        for permission in Permission::ALL {
+            let json = serde_json::to_string(&permission).unwrap();
+            assert_eq!(json, format!("\"{}\"", permission.name()));
+            assert_eq!(serde_json::from_str::<Permission>(&json).unwrap(), permission);
+        }
+        assert!(serde_json::from_str::<Permission>("\"orders:refund\"").is_err());
+    }
+}
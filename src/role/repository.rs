@@ -0,0 +1,66 @@
+use bson::{doc, to_bson, DateTime};
+use chrono::Utc;
+use mongodb::bson::oid::ObjectId;
+use mongodb::error::Result;
+use mongodb::options::ReturnDocument;
+use crate::database::MongoRepository;
+use crate::role::model::{Permission, Role};
+
+impl MongoRepository {
+    pub async fn create_role(&self, new_role: Role) -> Result<ObjectId> {
+        let role = self.role_collection.insert_one(new_role).await?;
+        Ok(role.inserted_id.as_object_id().unwrap())
+    }
+
+    pub async fn find_role_by_name(&self, store_id: &ObjectId, name: &str) -> Result<Option<Role>> {
+        let filter = doc! { "store_id": store_id, "name": name };
+        self.role_collection.find_one(filter).await
+    }
+
+    pub async fn find_roles(&self, store_id: &ObjectId) -> Result<Vec<Role>> {
+        let filter = doc! { "store_id": store_id };
+        let mut cursor = self.role_collection.find(filter).sort(doc! { "name": 1 }).await?;
+
+        let mut roles = vec![];
+        while cursor.advance().await? {
+            roles.push(cursor.deserialize_current()?);
+        }
+        Ok(roles)
+    }
+
+    pub async fn find_roles_by_names(&self, store_id: &ObjectId, names: &[String]) -> Result<Vec<Role>> {
+        let filter = doc! { "store_id": store_id, "name": { "$in": names } };
+        let mut cursor = self.role_collection.find(filter).await?;
+
+        let mut roles = vec![];
+        while cursor.advance().await? {
+            roles.push(cursor.deserialize_current()?);
+        }
+        Ok(roles)
+    }
+
+    pub async fn update_role_permissions(
+        &self,
+        store_id: &ObjectId,
+        name: &str,
+        permissions: &[Permission],
+    ) -> Result<Option<Role>> {
+        let filter = doc! { "store_id": store_id, "name": name };
+        let update = doc! {
+            "$set": {
+                "permissions": to_bson(permissions)?,
+                "update_at": DateTime::from_chrono(Utc::now()),
+            }
+        };
+        self.role_collection
+            .find_one_and_update(filter, update)
+            .return_document(ReturnDocument::After)
+            .await
+    }
+
+    pub async fn delete_role(&self, store_id: &ObjectId, name: &str) -> Result<bool> {
+        let filter = doc! { "store_id": store_id, "name": name };
+        let result = self.role_collection.delete_one(filter).await?;
+        Ok(result.deleted_count > 0)
+    }
+}
@@ -1,138 +1,219 @@
 use std::sync::Arc;
 use axum::{Extension, Json, Router};
 use axum::extract::Path;
-use axum::http::{HeaderMap, StatusCode};
+use axum::http::{Method, StatusCode};
 use axum::response::IntoResponse;
-use axum::routing::{delete, patch, post};
+use crate::auth::policy::PolicyRouter;
+use bcrypt::{hash, DEFAULT_COST};
+use crate::common::error::AppError;
+use crate::common::i18n::Message;
 use crate::common::response::ErrorResponse;
 use crate::common::types::Status;
 use crate::database::MongoRepository;
-use crate::store::model::{StoreResponse, Store};
-use crate::user::helper::validate_security_key;
+use crate::store::helper::{is_valid_sender_address, is_valid_sender_name};
+use crate::store::model::{StoreResponse, Store, UpdateLocaleRequest, UpdateSenderRequest, UpdateTwoFactorRequest, UpdateVerificationRequest};
+use crate::user::helper::validate_new_user;
+use crate::user::model::{Rank, RegisterRequest, User, UserProfileResponse};
 
 pub fn store_routes() -> Router {
     Router::new()
-        .route("/api/store/:store_name", post(create_store))
-        .route("/api/store/:store_name", delete(delete_store))
-        .route("/api/store/:store_name/:new_store_name", patch(rename_store))
+        .policy_route(Method::POST, "/api/store/:store_name", create_store)
+        .policy_route(Method::DELETE, "/api/store/:store_name", delete_store)
+        .policy_route(Method::PATCH, "/api/store/:store_name/:new_store_name", rename_store)
+        .policy_route(Method::PATCH, "/api/store/:store_name/settings/email", update_store_sender)
+        .policy_route(Method::PATCH, "/api/store/:store_name/settings/verification", update_store_verification)
+        .policy_route(Method::PATCH, "/api/store/:store_name/settings/two-factor", update_store_two_factor)
+        .policy_route(Method::PATCH, "/api/store/:store_name/settings/locale", update_store_locale)
+        .policy_route(Method::POST, "/api/store/:store_name/administrator", create_store_administrator)
 }
 
 pub async fn create_store(
-    headers: HeaderMap,
     Path(store_name): Path<String>,
     Extension(mongo_repo): Extension<Arc<MongoRepository>>,
-) -> Result<impl IntoResponse, StatusCode> {
-    if let Err(err) = validate_security_key(&headers) {
-        return Ok(err.into_response());
+) -> Result<impl IntoResponse, AppError> {
+    if mongo_repo.find_store_by_name(&store_name).await?.is_some() {
+        return Err(AppError::Conflict(Message::new("STORE_NAME_TAKEN")));
     }
 
-    if let Some(_) = mongo_repo.find_store_by_name(&store_name).await {
-        let error_response = ErrorResponse {
-            status: Status::Failure,
-            message: "이미 사용중인 스토어 이름입니다.".to_string(),
-        };
-        return Ok((StatusCode::CONFLICT, Json(error_response)).into_response());
+    let store = Store::new(store_name.to_string());
+    mongo_repo.create_store(store.clone()).await?;
+
+    let response = StoreResponse {
+        status: Status::Success,
+        store,
+    };
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+pub async fn delete_store(
+    Path(store_name): Path<String>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    if !mongo_repo.delete_store(&store_name).await? {
+        return Err(store_not_found(&store_name));
     }
 
-    let store = Store::new(store_name.to_string());
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("STORE_DELETED").arg(store_name).localize(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn rename_store(
+    Path((store_name, new_store_name)): Path<(String, String)>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    if mongo_repo.find_store_by_name(&store_name).await?.is_none() {
+        return Err(store_not_found(&store_name));
+    }
+
+    if !mongo_repo.rename_store(&store_name, &new_store_name).await? {
+        return Err(AppError::Internal(Message::new("STORE_RENAME_FAILED").arg(store_name)));
+    }
 
-    let result = mongo_repo.create_store(store.clone()).await;
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("STORE_RENAMED").arg(store_name).arg(new_store_name).localize(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
 
-    match result {
-        Ok(_) => {
-            let response = StoreResponse {
-                status: Status::Success,
-                store,
-            };
-            Ok((StatusCode::CREATED, Json(response)).into_response())
+pub async fn update_store_sender(
+    Path(store_name): Path<String>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Json(body): Json<UpdateSenderRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if let Some(name) = &body.sender_name {
+        if !is_valid_sender_name(name) {
+            return Err(AppError::Validation(Message::new("INVALID_SENDER_NAME")));
         }
-        Err(_) => {
-            let error_response = ErrorResponse {
-                status: Status::Error,
-                message: "스토어 생성을 실패하였습니다.".to_string(),
-            };
-            Ok((StatusCode::INTERNAL_SERVER_ERROR, Json(error_response)).into_response())
+    }
+
+    if let Some(address) = &body.sender_address {
+        if !is_valid_sender_address(address) {
+            return Err(AppError::Validation(Message::new("INVALID_SENDER_ADDRESS")));
         }
     }
+
+    if !mongo_repo
+        .update_store_sender(&store_name, body.sender_name.clone(), body.sender_address.clone())
+        .await?
+    {
+        return Err(store_not_found(&store_name));
+    }
+
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("STORE_SENDER_UPDATED").arg(store_name).localize(),
+    };
+    Ok((StatusCode::OK, Json(response)))
 }
 
-pub async fn delete_store(
-    headers: HeaderMap,
+pub async fn update_store_verification(
     Path(store_name): Path<String>,
     Extension(mongo_repo): Extension<Arc<MongoRepository>>,
-) -> Result<impl IntoResponse, StatusCode> {
-    if let Err(err) = validate_security_key(&headers) {
-        return Ok(err.into_response());
+    Json(body): Json<UpdateVerificationRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if !mongo_repo.update_store_verification(&store_name, body.require_verified_email).await? {
+        return Err(store_not_found(&store_name));
     }
 
-    let result = mongo_repo.delete_store(&store_name).await;
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("STORE_VERIFICATION_UPDATED").arg(store_name).localize(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
 
-    match result {
-        Ok(true) => {
-            let response = ErrorResponse {
-                status: Status::Success,
-                message: format!("스토어 '{}' 삭제 성공.", store_name),
-            };
-            Ok((StatusCode::OK, Json(response)).into_response())
-        }
-        Ok(false) => {
-            let error_response = ErrorResponse {
-                status: Status::Failure,
-                message: format!("스토어 '{}'를 찾을 수 없습니다.", store_name),
-            };
-            Ok((StatusCode::NOT_FOUND, Json(error_response)).into_response())
-        }
-        Err(_) => {
-            let error_response = ErrorResponse {
-                status: Status::Error,
-                message: "스토어 삭제에 실패하였습니다.".to_string(),
-            };
-            Ok((StatusCode::INTERNAL_SERVER_ERROR, Json(error_response)).into_response())
-        }
+pub async fn update_store_two_factor(
+    Path(store_name): Path<String>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Json(body): Json<UpdateTwoFactorRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if !mongo_repo.update_store_two_factor(&store_name, body.require_admin_2fa).await? {
+        return Err(store_not_found(&store_name));
     }
+
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("STORE_TWO_FACTOR_UPDATED").arg(store_name).localize(),
+    };
+    Ok((StatusCode::OK, Json(response)))
 }
 
-pub async fn rename_store(
-    headers: HeaderMap,
-    Path((store_name, new_store_name)): Path<(String, String)>,
+pub async fn update_store_locale(
+    Path(store_name): Path<String>,
     Extension(mongo_repo): Extension<Arc<MongoRepository>>,
-) -> Result<impl IntoResponse, StatusCode> {
-    if let Err(err) = validate_security_key(&headers) {
-        return Ok(err.into_response());
+    Json(body): Json<UpdateLocaleRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    if !mongo_repo.update_store_locale(&store_name, body.default_locale).await? {
+        return Err(store_not_found(&store_name));
     }
 
-    let existing_store = mongo_repo.find_store_by_name(&store_name).await;
-    if existing_store.is_none() {
-        let error_response = ErrorResponse {
-            status: Status::Failure,
-            message: format!("스토어 '{}'를 찾을 수 없습니다.", store_name),
-        };
-        return Ok((StatusCode::NOT_FOUND, Json(error_response)).into_response());
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("STORE_LOCALE_UPDATED").arg(store_name).localize(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+// 스토어의 첫 관리자를 만드는 부트스트랩 경로라 관리자가 이미 있으면 거부합니다.
+pub async fn create_store_administrator(
+    Path(store_name): Path<String>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Json(body): Json<RegisterRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let store = mongo_repo
+        .find_store_by_name(&store_name)
+        .await?
+        .ok_or_else(|| store_not_found(&store_name))?;
+    let store_id = store.object_id.unwrap();
+
+    if !mongo_repo.find_users_by_rank(&store_id, Rank::Administrator).await?.is_empty() {
+        return Err(AppError::Conflict(Message::new("ADMINISTRATOR_ALREADY_EXISTS")));
     }
 
-    let result = mongo_repo.rename_store(&store_name, &new_store_name).await;
+    validate_new_user(&mongo_repo, &store_id, &body).await?;
+    let password = hash(&body.password, DEFAULT_COST)?;
 
-    match result {
-        Ok(true) => {
-            let response = ErrorResponse {
-                status: Status::Success,
-                message: format!("스토어 이름 '{}'에서 '{}'로 성공적으로 변경되었습니다.", store_name, new_store_name),
-            };
-            Ok((StatusCode::OK, Json(response)).into_response())
-        }
-        Ok(false) => {
-            let error_response = ErrorResponse {
-                status: Status::Failure,
-                message: format!("스토어 이름 '{}'을 변경하는 데 실패하였습니다.", store_name),
-            };
-            Ok((StatusCode::INTERNAL_SERVER_ERROR, Json(error_response)).into_response())
-        }
-        Err(_) => {
-            let error_response = ErrorResponse {
-                status: Status::Error,
-                message: "스토어 이름 변경에 실패하였습니다.".to_string(),
-            };
-            Ok((StatusCode::INTERNAL_SERVER_ERROR, Json(error_response)).into_response())
+    if !mongo_repo.claim_administrator_bootstrap(&store_id).await? {
+        return Err(AppError::Conflict(Message::new("ADMINISTRATOR_ALREADY_EXISTS")));
+    }
+
+    let mut administrator = User {
+        store_id: Some(store_id),
+        object_id: None,
+        user_id: body.user_id,
+        email: body.email,
+        pending_email: None,
+        password,
+        rank: Rank::Administrator,
+        roles: vec![],
+        verified: true,
+        suspended: false,
+        verification_sent_at: None,
+        totp_enabled: false,
+        totp_secret: None,
+        totp_pending_secret: None,
+        totp_last_step: None,
+        recovery_codes: vec![],
+    };
+    match mongo_repo.create_user(administrator.clone()).await {
+        Ok(user_id) => administrator.object_id = Some(user_id),
+        Err(err) => {
+            mongo_repo.release_administrator_bootstrap(&store_id).await?;
+            return Err(err.into());
         }
     }
-}
\ No newline at end of file
+
+    let response = UserProfileResponse {
+        status: Status::Success,
+        user: administrator.into(),
+    };
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+fn store_not_found(store_name: &str) -> AppError {
+    AppError::NotFound(Message::new("STORE_NAME_NOT_FOUND").arg(store_name))
+}
@@ -1,37 +1,56 @@
 use std::sync::Arc;
-use axum::http::StatusCode;
-use axum::Json;
 use axum_extra::headers::HeaderMap;
-use crate::common::response::ErrorResponse;
-use crate::common::types::Status;
+use crate::common::error::AppError;
+use crate::common::i18n::Message;
 use crate::database::MongoRepository;
 use crate::store::model::Store;
+use crate::user::helper::is_valid_email;
 
 pub async fn get_store_from_headers(
     headers: &HeaderMap,
     mongo_repo: &Arc<MongoRepository>,
-) -> Result<Store, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Store, AppError> {
     let store_name = headers
         .get("X-Store-Name")
         .and_then(|h| h.to_str().ok())
         .unwrap_or("");
 
     if store_name.is_empty() {
-        let error_response = ErrorResponse {
-            status: Status::Failure,
-            message: "스토어 이름을 제공해주세요.".to_string(),
-        };
-        return Err((StatusCode::BAD_REQUEST, Json(error_response)));
+        return Err(AppError::Validation(Message::new("STORE_NAME_REQUIRED")));
     }
 
-    match mongo_repo.find_store_by_name(store_name).await {
-        Some(store) => Ok(store),
-        None => {
-            let error_response = ErrorResponse {
-                status: Status::Failure,
-                message: "존재하지 않는 스토어입니다.".to_string(),
-            };
-            Err((StatusCode::BAD_REQUEST, Json(error_response)))
+    mongo_repo
+        .find_store_by_name(store_name)
+        .await?
+        .ok_or_else(|| AppError::NotFound(Message::new("STORE_NOT_FOUND")))
+}
+
+// 발신자는 "이름 <주소>" 형태로 헤더에 그대로 들어가므로 헤더를 깨뜨릴 수 있는 문자를 막습니다.
+pub fn is_valid_sender_name(name: &str) -> bool {
+    !name.trim().is_empty() && !name.chars().any(|c| matches!(c, '\r' | '\n' | '<' | '>' | '"'))
+}
+
+pub fn is_valid_sender_address(address: &str) -> bool {
+    is_valid_email(address) && !address.contains(['<', '>', '"'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sender_name_rejects_header_breaking_characters() {
+        assert!(is_valid_sender_name("Acme Shop"));
+        for name in ["Acme\r\nBcc: a@b.com", "Acme <x@y.com>", "\"Acme\"", "   "] {
+            assert!(!is_valid_sender_name(name), "{:?}", name);
+        }
+    }
+
+    #[test]
+    fn sender_address_must_be_a_plain_email() {
+        assert!(is_valid_sender_address("shop@example.com"));
+        for address in ["shop", "shop@example", "a>b@example.com", "shop@example.com\n"] {
+            assert!(!is_valid_sender_address(address), "{:?}", address);
         }
     }
-}
\ No newline at end of file
+}
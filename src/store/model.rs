@@ -1,13 +1,28 @@
 use bson::oid::ObjectId;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use crate::common::i18n::Locale;
 use crate::common::types::Status;
+use crate::config::CONFIG;
+use crate::store::helper::{is_valid_sender_address, is_valid_sender_name};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Store {
     #[serde(rename = "_id", skip_serializing_if = "Option::is_none")]
     pub object_id: Option<ObjectId>,
     pub name: String,
+    #[serde(default)]
+    pub sender_name: Option<String>,
+    #[serde(default)]
+    pub sender_address: Option<String>,
+    #[serde(default)]
+    pub require_verified_email: bool,
+    #[serde(default)]
+    pub require_admin_2fa: bool,
+    #[serde(default)]
+    pub default_locale: Locale,
+    #[serde(default)]
+    pub administrator_bootstrapped: bool,
     pub create_at: DateTime<Utc>,
     pub update_at: DateTime<Utc>,
 }
@@ -18,6 +33,12 @@ impl Store {
         Store {
             object_id: None,
             name,
+            sender_name: None,
+            sender_address: None,
+            require_verified_email: false,
+            require_admin_2fa: false,
+            default_locale: Locale::default(),
+            administrator_bootstrapped: false,
             create_at: now,
             update_at: now,
         }
@@ -28,10 +49,45 @@ impl Store {
         let now = Utc::now();
         self.update_at = now;
     }
+
+    pub fn sender(&self) -> String {
+        let name = self
+            .sender_name
+            .as_deref()
+            .filter(|name| is_valid_sender_name(name))
+            .unwrap_or(&CONFIG.mail_from_name);
+        let address = self
+            .sender_address
+            .as_deref()
+            .filter(|address| is_valid_sender_address(address))
+            .unwrap_or(&CONFIG.mail_from_address);
+        format!("{} <{}>", name, address)
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct StoreResponse {
     pub status: Status,
     pub store: Store,
-}
\ No newline at end of file
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UpdateSenderRequest {
+    pub sender_name: Option<String>,
+    pub sender_address: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UpdateVerificationRequest {
+    pub require_verified_email: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UpdateTwoFactorRequest {
+    pub require_admin_2fa: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UpdateLocaleRequest {
+    pub default_locale: Locale,
+}
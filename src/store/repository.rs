@@ -1,6 +1,8 @@
-use bson::doc;
+use bson::{doc, to_bson};
+use chrono::Utc;
 use mongodb::bson::oid::ObjectId;
 use mongodb::error::Result;
+use crate::common::i18n::Locale;
 use crate::database::MongoRepository;
 use crate::store::model::Store;
 
@@ -16,9 +18,14 @@ impl MongoRepository {
         Ok(result.deleted_count > 0)
     }
 
-    pub async fn find_store_by_name(&self, name: &str) -> Option<Store> {
+    pub async fn find_store_by_name(&self, name: &str) -> Result<Option<Store>> {
         let filter = doc! { "name": name };
-        self.store_collection.find_one(filter).await.unwrap_or(None)
+        self.store_collection.find_one(filter).await
+    }
+
+    pub async fn find_store_by_id(&self, id: &ObjectId) -> Result<Option<Store>> {
+        let filter = doc! { "_id": id };
+        self.store_collection.find_one(filter).await
     }
 
     pub async fn rename_store(&self, old_name: &str, new_name: &str) -> Result<bool> {
@@ -28,4 +35,78 @@ impl MongoRepository {
         let result = self.store_collection.update_one(filter, update).await?;
         Ok(result.modified_count > 0)
     }
-}
\ No newline at end of file
+
+    pub async fn update_store_sender(
+        &self,
+        name: &str,
+        sender_name: Option<String>,
+        sender_address: Option<String>,
+    ) -> Result<bool> {
+        let filter = doc! { "name": name };
+        let update = doc! {
+            "$set": {
+                "sender_name": sender_name,
+                "sender_address": sender_address,
+                "update_at": to_bson(&Utc::now())?,
+            }
+        };
+
+        let result = self.store_collection.update_one(filter, update).await?;
+        Ok(result.matched_count > 0)
+    }
+
+    pub async fn update_store_verification(&self, name: &str, require_verified_email: bool) -> Result<bool> {
+        let filter = doc! { "name": name };
+        let update = doc! {
+            "$set": {
+                "require_verified_email": require_verified_email,
+                "update_at": to_bson(&Utc::now())?,
+            }
+        };
+
+        let result = self.store_collection.update_one(filter, update).await?;
+        Ok(result.matched_count > 0)
+    }
+
+    pub async fn update_store_two_factor(&self, name: &str, require_admin_2fa: bool) -> Result<bool> {
+        let filter = doc! { "name": name };
+        let update = doc! {
+            "$set": {
+                "require_admin_2fa": require_admin_2fa,
+                "update_at": to_bson(&Utc::now())?,
+            }
+        };
+
+        let result = self.store_collection.update_one(filter, update).await?;
+        Ok(result.matched_count > 0)
+    }
+
+    pub async fn update_store_locale(&self, name: &str, default_locale: Locale) -> Result<bool> {
+        let filter = doc! { "name": name };
+        let update = doc! {
+            "$set": {
+                "default_locale": to_bson(&default_locale)?,
+                "update_at": to_bson(&Utc::now())?,
+            }
+        };
+
+        let result = self.store_collection.update_one(filter, update).await?;
+        Ok(result.matched_count > 0)
+    }
+
+    // 첫 관리자 생성은 이 플래그를 먼저 차지한 요청 하나만 진행합니다.
+    pub async fn claim_administrator_bootstrap(&self, store_id: &ObjectId) -> Result<bool> {
+        let filter = doc! { "_id": store_id, "administrator_bootstrapped": { "$ne": true } };
+        let update = doc! { "$set": { "administrator_bootstrapped": true } };
+
+        let result = self.store_collection.update_one(filter, update).await?;
+        Ok(result.modified_count > 0)
+    }
+
+    pub async fn release_administrator_bootstrap(&self, store_id: &ObjectId) -> Result<()> {
+        let filter = doc! { "_id": store_id };
+        let update = doc! { "$set": { "administrator_bootstrapped": false } };
+        self.store_collection.update_one(filter, update).await?;
+        Ok(())
+    }
+}
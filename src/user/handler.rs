@@ -1,157 +1,520 @@
-use axum::http::{HeaderMap, StatusCode};
+use axum::http::{Method, StatusCode};
 use axum::{Json, Router};
-use axum::response::IntoResponse;
+use axum::response::{IntoResponse, Response};
 use bcrypt::{hash, verify, DEFAULT_COST};
+use std::collections::BTreeSet;
+use std::str::FromStr;
 use std::sync::Arc;
-use axum::extract::Extension;
-use axum::routing::post;
-use crate::common::jwt::generate_jwt;
+use axum::extract::{Extension, Path, Query};
+use crate::auth::policy::PolicyRouter;
+use bson::oid::ObjectId;
+use chrono::Utc;
+use crate::auth::extractor::{AuthUser, Authorized, ClientIp, CurrentStore, SessionClient};
+use crate::auth::helper::{
+    ensure_login_allowed, invalid_credentials, issue_token_response, login_attempt_keys, DUMMY_PASSWORD_HASH,
+};
+use crate::auth::model::{PublicSession, SessionListResponse, TwoFactorChallengeResponse};
+use crate::common::email::Mailer;
+use crate::common::error::{is_duplicate_key, AppError};
+use crate::common::id::parse_object_id;
+use crate::common::i18n::Message;
+use crate::common::pagination::{page_limit, parse_cursor, split_page};
+use crate::common::jwt::{generate_challenge_jwt, validate_verification_jwt, EMAIL_CHANGE_PURPOSE};
 use crate::common::types::Status;
 use crate::database::MongoRepository;
 use crate::common::response::ErrorResponse;
 use crate::config::CONFIG;
-use crate::store::helper::get_store_from_headers;
-use crate::user::model::{LoginRequest, Rank, RegisterRequest, User, UserResponse};
+use crate::role::handler::role_not_found;
+use crate::role::helper::{effective_permissions, ensure_can_grant};
+use crate::role::model::{Permission, UpdateUserRolesRequest};
+use crate::auth::helper::{revoke_all_sessions, revoke_session, start_session};
+use crate::user::helper::{
+    delete_account, ensure_not_suspended, is_valid_email, spawn_email_change_email, spawn_verification_email,
+    validate_new_password, validate_new_user, verify_current_password,
+};
+use crate::user::model::{
+    AdminUserListQuery, ChangePasswordRequest, DeleteAccountRequest, LoginRequest, Rank, RegisterRequest,
+    UpdateProfileRequest, UpdateRankRequest, User, UserFilter, UserListResponse, UserProfileResponse, VerifyEmailRequest,
+};
 
 pub fn user_routes() -> Router {
     Router::new()
-        .route("/api/auth/register", post(register_user))
-        .route("/api/auth/login", post(login_user))
+        .policy_route(Method::POST, "/api/auth/register", register_user)
+        .policy_route(Method::POST, "/api/auth/login", login_user)
+        .policy_route(Method::POST, "/api/auth/email/verify", verify_email)
+        .policy_route(Method::POST, "/api/auth/email/resend", resend_verification_email)
+        .policy_route(Method::GET, "/api/users/me", get_my_profile)
+        .policy_route(Method::PATCH, "/api/users/me", update_my_profile)
+        .policy_route(Method::DELETE, "/api/users/me", delete_my_account)
+        .policy_route(Method::POST, "/api/users/me/password", change_my_password)
+        .policy_route(Method::GET, "/api/users/me/sessions", list_my_sessions)
+        .policy_route(Method::DELETE, "/api/users/me/sessions/:session_id", revoke_my_session)
+        .policy_route(Method::GET, "/api/admin/users", list_store_users)
+        .policy_route(Method::PATCH, "/api/admin/users/:user_id/rank", update_user_rank)
+        .policy_route(Method::PUT, "/api/admin/users/:user_id/roles", update_user_roles)
+        .policy_route(Method::POST, "/api/admin/users/:user_id/suspend", suspend_user)
+        .policy_route(Method::POST, "/api/admin/users/:user_id/unsuspend", unsuspend_user)
+        .policy_route(Method::GET, "/api/admin/users/:user_id/sessions", list_user_sessions)
+        .policy_route(Method::DELETE, "/api/admin/users/:user_id/sessions/:session_id", revoke_user_session)
 }
 
 pub async fn register_user(
-    headers: HeaderMap,
+    CurrentStore(store): CurrentStore,
+    client: SessionClient,
     Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Extension(mailer): Extension<Arc<dyn Mailer>>,
     Json(body): Json<RegisterRequest>,
-) -> Result<impl IntoResponse, StatusCode> {
-    let store = match get_store_from_headers(&headers, &mongo_repo).await {
-        Ok(store) => store,
-        Err(err) => return Ok(err.into_response()),
+) -> Result<impl IntoResponse, AppError> {
+    validate_new_user(&mongo_repo, &store.object_id.unwrap(), &body).await?;
+
+    let hashed_password = hash(&body.password, DEFAULT_COST)?;
+
+    let new_user = User {
+        store_id: store.object_id,
+        object_id: None,
+        user_id: body.user_id.clone(),
+        email: body.email.clone(),
+        pending_email: None,
+        password: hashed_password,
+        rank: Rank::Customer,
+        roles: vec![],
+        verified: false,
+        suspended: false,
+        verification_sent_at: Some(Utc::now()),
+        totp_enabled: false,
+        totp_secret: None,
+        totp_pending_secret: None,
+        totp_last_step: None,
+        recovery_codes: vec![],
     };
 
-    if body.password != body.confirm_password {
-        let error_response = ErrorResponse {
-            status: Status::Failure,
-            message: "비밀번호와 비밀번호 확인이 일치하지 않습니다.".to_string(),
+    let user_id = mongo_repo.create_user(new_user.clone()).await?;
+    let store_id = store.object_id.unwrap();
+    spawn_verification_email(mailer, store, User { object_id: Some(user_id), ..new_user });
+
+    let session_id = start_session(&mongo_repo, store_id, user_id, client).await?;
+    issue_token_response(&mongo_repo, user_id, store_id, Some(session_id)).await
+}
+
+pub async fn login_user(
+    CurrentStore(store): CurrentStore,
+    ClientIp(ip): ClientIp,
+    client: SessionClient,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Json(body): Json<LoginRequest>,
+) -> Result<Response, AppError> {
+    let store_id = store.object_id.unwrap();
+
+    let attempt = ensure_login_allowed(&mongo_repo, &store_id, login_attempt_keys(&body.user_id, ip)).await?;
+
+    let user = mongo_repo.find_user_by_user_id(&store_id, &body.user_id).await?;
+    let password_hash = user.as_ref().map_or(DUMMY_PASSWORD_HASH.as_str(), |user| user.password.as_str());
+
+    let is_password_valid = verify(&body.password, password_hash)?;
+
+    let user = match user {
+        Some(user) if is_password_valid => user,
+        _ => {
+            attempt.fail().await;
+            return Err(invalid_credentials());
+        }
+    };
+
+    ensure_not_suspended(&user)?;
+
+    let user_object_id = user
+        .object_id
+        .ok_or_else(|| AppError::Internal(Message::new("INVALID_USER_RECORD")))?;
+
+    if user.totp_enabled {
+        let response = TwoFactorChallengeResponse {
+            status: Status::Success,
+            two_factor_required: true,
+            challenge_token: generate_challenge_jwt(user_object_id, store_id, &CONFIG.jwt_secret),
         };
-        return Ok((StatusCode::BAD_REQUEST, Json(error_response)).into_response());
+        return Ok((StatusCode::OK, Json(response)).into_response());
     }
 
-    if let Some(_) = mongo_repo.find_user_by_email(&store.object_id.unwrap(), &body.email).await {
-        let error_response = ErrorResponse {
-            status: Status::Failure,
-            message: "이미 가입된 이메일입니다.".to_string(),
-        };
-        return Ok((StatusCode::CONFLICT, Json(error_response)).into_response());
+    attempt.succeed().await;
+
+    let session_id = start_session(&mongo_repo, store_id, user_object_id, client).await?;
+    issue_token_response(&mongo_repo, user_object_id, store_id, Some(session_id)).await
+}
+
+pub async fn verify_email(
+    CurrentStore(store): CurrentStore,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Json(body): Json<VerifyEmailRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let store_id = store.object_id.unwrap();
+    let invalid_link = || AppError::Validation(Message::new("INVALID_VERIFICATION_LINK"));
+
+    let claims = validate_verification_jwt(&body.token)
+        .filter(|claims| claims.store_id == store_id.to_hex())
+        .ok_or_else(invalid_link)?;
+    let user_id = ObjectId::from_str(&claims.id).map_err(|_| invalid_link())?;
+
+    let message = if claims.purpose == EMAIL_CHANGE_PURPOSE {
+        // 같은 주소로의 동시 변경은 {store_id, email} 고유 인덱스가 막습니다.
+        match mongo_repo.confirm_email_change(&store_id, &user_id, &claims.email).await {
+            Ok(true) => {}
+            Ok(false) => return Err(invalid_link()),
+            Err(err) if is_duplicate_key(&err) => return Err(AppError::Conflict(Message::new("EMAIL_TAKEN"))),
+            Err(err) => return Err(err.into()),
+        }
+        mongo_repo.update_user_restock_email(&store_id, &user_id, &claims.email).await?;
+        mongo_repo.delete_user_password_reset_tokens(&store_id, &user_id).await?;
+        Message::new("EMAIL_CHANGED")
+    } else {
+        if !mongo_repo.mark_user_verified(&store_id, &user_id, &claims.email).await? {
+            return Err(invalid_link());
+        }
+        Message::new("EMAIL_VERIFIED")
+    };
+
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: message.localize(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn resend_verification_email(
+    AuthUser { store, user, .. }: AuthUser,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Extension(mailer): Extension<Arc<dyn Mailer>>,
+) -> Result<impl IntoResponse, AppError> {
+    if user.verified {
+        return Err(AppError::Conflict(Message::new("EMAIL_ALREADY_VERIFIED")));
     }
 
-    if let Some(_) = mongo_repo.find_user_by_user_id(&store.object_id.unwrap(), &body.user_id).await {
-        let error_response = ErrorResponse {
-            status: Status::Failure,
-            message: "이미 사용중인 유저 아이디입니다.".to_string(),
-        };
-        return Ok((StatusCode::CONFLICT, Json(error_response)).into_response());
+    if !mongo_repo.claim_verification_resend(&store.object_id.unwrap(), &user.object_id.unwrap()).await? {
+        return Err(AppError::TooManyRequests {
+            message: Message::new("VERIFICATION_RESEND_COOLDOWN").arg(CONFIG.email_verification_cooldown_secs),
+            retry_after: Some(CONFIG.email_verification_cooldown_secs),
+        });
     }
 
-    let hashed_password = match hash(&body.password, DEFAULT_COST) {
-        Ok(hashed) => hashed,
-        Err(_) => {
-            let error_response = ErrorResponse {
-                status: Status::Error,
-                message: "비밀번호 해시화에 실패했습니다.".to_string(),
-            };
-            return Ok((StatusCode::INTERNAL_SERVER_ERROR, Json(error_response)).into_response());
-        }
+    spawn_verification_email(mailer, store, user);
+
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("VERIFICATION_EMAIL_RESENT").localize(),
     };
+    Ok((StatusCode::OK, Json(response)))
+}
 
-    let new_user = User {
-        store_id: store.object_id,
-        object_id: None,
-        user_id: body.user_id.clone(),
-        email: body.email.clone(),
-        password: hashed_password,
-        rank: Rank::Customer,
+pub async fn get_my_profile(AuthUser { user, .. }: AuthUser) -> Result<impl IntoResponse, AppError> {
+    let response = UserProfileResponse {
+        status: Status::Success,
+        user: user.into(),
     };
+    Ok((StatusCode::OK, Json(response)))
+}
 
-    let result = mongo_repo.create_user(new_user.clone()).await;
+pub async fn update_my_profile(
+    AuthUser { store, mut user, .. }: AuthUser,
+    ClientIp(ip): ClientIp,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Extension(mailer): Extension<Arc<dyn Mailer>>,
+    Json(body): Json<UpdateProfileRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let store_id = store.object_id.unwrap();
+    verify_current_password(&mongo_repo, &store_id, &user, &body.current_password, ip).await?;
 
-    match result {
-        Ok(_) => {
-            let token = generate_jwt(result.unwrap().to_string().parse().unwrap(), store.object_id.unwrap(), &CONFIG.jwt_secret);
+    if let Some(email) = body.email {
+        if !is_valid_email(&email) {
+            return Err(AppError::Validation(Message::new("INVALID_EMAIL")));
+        }
 
-            let response = UserResponse {
-                status: Status::Success,
-                token,
-            };
-            Ok((StatusCode::OK, Json(response)).into_response())
+        if email == user.email {
+            return Err(AppError::Validation(Message::new("EMAIL_UNCHANGED")));
         }
-        Err(_) => {
-            let error_response = ErrorResponse {
-                status: Status::Error,
-                message: "유저 생성에 실패하였습니다.".to_string(),
-            };
-            Ok((StatusCode::INTERNAL_SERVER_ERROR, Json(error_response)).into_response())
+
+        if mongo_repo.find_user_by_email(&store_id, &email).await?.is_some() {
+            return Err(AppError::Conflict(Message::new("EMAIL_TAKEN")));
+        }
+
+        if !mongo_repo.request_email_change(&store_id, &user.object_id.unwrap(), &email).await? {
+            return Err(AppError::TooManyRequests {
+                message: Message::new("VERIFICATION_RESEND_COOLDOWN").arg(CONFIG.email_verification_cooldown_secs),
+                retry_after: Some(CONFIG.email_verification_cooldown_secs),
+            });
         }
+
+        spawn_email_change_email(mailer, store, user.clone(), email.clone());
+        user.pending_email = Some(email);
     }
+
+    let response = UserProfileResponse {
+        status: Status::Success,
+        user: user.into(),
+    };
+    Ok((StatusCode::OK, Json(response)))
 }
 
-pub async fn login_user(
-    headers: HeaderMap,
+pub async fn change_my_password(
+    AuthUser { store, user, .. }: AuthUser,
+    ClientIp(ip): ClientIp,
     Extension(mongo_repo): Extension<Arc<MongoRepository>>,
-    Json(body): Json<LoginRequest>,
-) -> Result<impl IntoResponse, StatusCode> {
-    let store = match get_store_from_headers(&headers, &mongo_repo).await {
-        Ok(store) => store,
-        Err(err) => return Ok(err.into_response()),
-    };
-
-    let user = match mongo_repo.find_user_by_user_id(&store.object_id.unwrap(), &body.user_id).await {
-        Some(user) => user,
-        None => {
-            let error_response = ErrorResponse {
-                status: Status::Failure,
-                message: "존재하지 않는 사용자입니다.".to_string(),
-            };
-            return Ok((StatusCode::UNAUTHORIZED, Json(error_response)).into_response());
-        }
+    Json(body): Json<ChangePasswordRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let store_id = store.object_id.unwrap();
+    let user_id = user.object_id.unwrap();
+
+    validate_new_password(&body.password, &body.confirm_password)?;
+
+    verify_current_password(&mongo_repo, &store_id, &user, &body.current_password, ip).await?;
+
+    let hashed_password = hash(&body.password, DEFAULT_COST)?;
+    mongo_repo.update_user_password(&store_id, &user_id, &hashed_password).await?;
+    mongo_repo.delete_user_password_reset_tokens(&store_id, &user_id).await?;
+    revoke_all_sessions(&mongo_repo, store_id, user_id).await?;
+
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("PASSWORD_CHANGED").localize(),
     };
+    Ok((StatusCode::OK, Json(response)))
+}
 
-    let is_password_valid = match verify(&body.password, &user.password) {
-        Ok(valid) => valid,
-        Err(_) => {
-            let error_response = ErrorResponse {
-                status: Status::Error,
-                message: "비밀번호 검증에 실패하였습니다.".to_string(),
-            };
-            return Ok((StatusCode::INTERNAL_SERVER_ERROR, Json(error_response)).into_response());
-        }
+pub async fn delete_my_account(
+    AuthUser { store, user, .. }: AuthUser,
+    ClientIp(ip): ClientIp,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Extension(mailer): Extension<Arc<dyn Mailer>>,
+    Json(body): Json<DeleteAccountRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_current_password(&mongo_repo, &store.object_id.unwrap(), &user, &body.password, ip).await?;
+
+    delete_account(&mongo_repo, &mailer, &store, &user).await?;
+
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("ACCOUNT_DELETED").localize(),
     };
+    Ok((StatusCode::OK, Json(response)))
+}
 
-    if !is_password_valid {
-        let error_response = ErrorResponse {
-            status: Status::Failure,
-            message: "잘못된 비밀번호입니다.".to_string(),
-        };
-        return Ok((StatusCode::UNAUTHORIZED, Json(error_response)).into_response());
+pub async fn list_my_sessions(
+    AuthUser { store, user, claims }: AuthUser,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    let sessions = mongo_repo
+        .find_user_sessions(&store.object_id.unwrap(), &user.object_id.unwrap())
+        .await?;
+
+    let response = SessionListResponse {
+        status: Status::Success,
+        sessions: sessions
+            .into_iter()
+            .map(|session| PublicSession::new(session, Some(&claims.sid)))
+            .collect(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn revoke_my_session(
+    AuthUser { store, user, .. }: AuthUser,
+    Path(session_id): Path<String>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    let session_id = parse_object_id(&session_id, "INVALID_SESSION_ID")?;
+
+    if !revoke_session(&mongo_repo, store.object_id.unwrap(), user.object_id.unwrap(), session_id).await? {
+        return Err(AppError::NotFound(Message::new("SESSION_NOT_FOUND")));
     }
 
-    let user_object_id = match user.object_id {
-        Some(id) => id,
-        None => {
-            let error_response = ErrorResponse {
-                status: Status::Error,
-                message: "유저 정보가 유효하지 않습니다.".to_string(),
-            };
-            return Ok((StatusCode::INTERNAL_SERVER_ERROR, Json(error_response)).into_response());
-        }
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("SESSION_REVOKED").localize(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn list_store_users(
+    Authorized { store, .. }: Authorized,
+    Query(query): Query<AdminUserListQuery>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    let cursor = parse_cursor(&query.cursor)?;
+    let limit = page_limit(query.limit);
+
+    let filter = UserFilter {
+        query: query.query,
+        rank: query.rank,
+        suspended: query.suspended,
+    };
+
+    let users = mongo_repo.find_users(&store.object_id.unwrap(), &filter, cursor, limit + 1).await?;
+    let (users, next_cursor) = split_page(users, limit, |user| user.object_id);
+
+    let response = UserListResponse {
+        status: Status::Success,
+        users: users.into_iter().map(Into::into).collect(),
+        next_cursor,
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn update_user_rank(
+    Authorized { store, user: admin, permissions: granter }: Authorized,
+    Path(user_id): Path<String>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Json(body): Json<UpdateRankRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    ensure_can_grant(&granter, body.rank.permissions())?;
+
+    let store_id = store.object_id.unwrap();
+    let target = find_managed_user(&mongo_repo, &store_id, &admin, &granter, &user_id).await?;
+
+    let user = mongo_repo
+        .update_user_rank(&store_id, &target.object_id.unwrap(), &body.rank)
+        .await?
+        .ok_or_else(|| AppError::NotFound(Message::new("USER_NOT_FOUND")))?;
+
+    let response = UserProfileResponse {
+        status: Status::Success,
+        user: user.into(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn update_user_roles(
+    Authorized { store, user: admin, permissions: granter }: Authorized,
+    Path(user_id): Path<String>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+    Json(body): Json<UpdateUserRolesRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let store_id = store.object_id.unwrap();
+    let target = find_managed_user(&mongo_repo, &store_id, &admin, &granter, &user_id).await?;
+
+    let mut role_names = body.roles;
+    role_names.sort();
+    role_names.dedup();
+
+    let roles = mongo_repo.find_roles_by_names(&store_id, &role_names).await?;
+    if let Some(missing) = role_names.iter().find(|name| !roles.iter().any(|role| &role.name == *name)) {
+        return Err(role_not_found(missing));
+    }
+    ensure_can_grant(&granter, roles.iter().flat_map(|role| &role.permissions))?;
+
+    let user = mongo_repo
+        .set_user_roles(&store_id, &target.object_id.unwrap(), &role_names)
+        .await?
+        .ok_or_else(|| AppError::NotFound(Message::new("USER_NOT_FOUND")))?;
+
+    let response = UserProfileResponse {
+        status: Status::Success,
+        user: user.into(),
     };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn suspend_user(
+    Authorized { store, user: admin, permissions: granter }: Authorized,
+    Path(user_id): Path<String>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    let store_id = store.object_id.unwrap();
+    let target = find_managed_user(&mongo_repo, &store_id, &admin, &granter, &user_id).await?;
+    let target_id = target.object_id.unwrap();
 
-    let token = generate_jwt(user_object_id, store.object_id.unwrap(), &CONFIG.jwt_secret);
+    let user = mongo_repo
+        .set_user_suspended(&store_id, &target_id, true)
+        .await?
+        .ok_or_else(|| AppError::NotFound(Message::new("USER_NOT_FOUND")))?;
+    revoke_all_sessions(&mongo_repo, store_id, target_id).await?;
 
-    let response = UserResponse {
+    let response = UserProfileResponse {
         status: Status::Success,
-        token,
+        user: user.into(),
     };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn unsuspend_user(
+    Authorized { store, user: admin, permissions: granter }: Authorized,
+    Path(user_id): Path<String>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    let store_id = store.object_id.unwrap();
+    let target = find_managed_user(&mongo_repo, &store_id, &admin, &granter, &user_id).await?;
+
+    let user = mongo_repo
+        .set_user_suspended(&store_id, &target.object_id.unwrap(), false)
+        .await?
+        .ok_or_else(|| AppError::NotFound(Message::new("USER_NOT_FOUND")))?;
+
+    let response = UserProfileResponse {
+        status: Status::Success,
+        user: user.into(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn list_user_sessions(
+    Authorized { store, .. }: Authorized,
+    Path(user_id): Path<String>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    let store_id = store.object_id.unwrap();
+    let target = mongo_repo
+        .find_user_by_user_id(&store_id, &user_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(Message::new("USER_NOT_FOUND")))?;
+
+    let sessions = mongo_repo.find_user_sessions(&store_id, &target.object_id.unwrap()).await?;
 
-    Ok((StatusCode::OK, Json(response)).into_response())
-}
\ No newline at end of file
+    let response = SessionListResponse {
+        status: Status::Success,
+        sessions: sessions.into_iter().map(|session| PublicSession::new(session, None)).collect(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+pub async fn revoke_user_session(
+    Authorized { store, user: admin, permissions: granter }: Authorized,
+    Path((user_id, session_id)): Path<(String, String)>,
+    Extension(mongo_repo): Extension<Arc<MongoRepository>>,
+) -> Result<impl IntoResponse, AppError> {
+    let store_id = store.object_id.unwrap();
+    let session_id = parse_object_id(&session_id, "INVALID_SESSION_ID")?;
+    let target = find_managed_user(&mongo_repo, &store_id, &admin, &granter, &user_id).await?;
+
+    if !revoke_session(&mongo_repo, store_id, target.object_id.unwrap(), session_id).await? {
+        return Err(AppError::NotFound(Message::new("SESSION_NOT_FOUND")));
+    }
+
+    let response = ErrorResponse {
+        status: Status::Success,
+        message: Message::new("SESSION_REVOKED").localize(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+// 관리자가 자기 자신의 등급을 내리거나 정지해 스토어에 관리자가 남지 않는 상황을 막고,
+// 자신에게 없는 권한을 가진 유저는 관리하지 못하게 합니다.
+async fn find_managed_user(
+    mongo_repo: &Arc<MongoRepository>,
+    store_id: &ObjectId,
+    admin: &User,
+    granter: &BTreeSet<Permission>,
+    user_id: &str,
+) -> Result<User, AppError> {
+    if admin.user_id == user_id {
+        return Err(AppError::Forbidden(Message::new("CANNOT_MANAGE_SELF")));
+    }
+
+    let target = mongo_repo
+        .find_user_by_user_id(store_id, user_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(Message::new("USER_NOT_FOUND")))?;
+
+    let target_permissions = effective_permissions(mongo_repo, store_id, &target).await?;
+    if !target_permissions.is_subset(granter) {
+        return Err(AppError::Forbidden(Message::new("CANNOT_MANAGE_PRIVILEGED_USER")));
+    }
+
+    Ok(target)
+}
@@ -1,127 +1,266 @@
+use std::net::IpAddr;
 use std::str::FromStr;
 use std::sync::Arc;
 use axum::http::HeaderMap;
-use axum::{Json};
-use axum::http::StatusCode;
+use bcrypt::verify;
+use bson::DateTime;
 use bson::oid::ObjectId;
-use crate::common::jwt::validate_jwt;
-use crate::common::response::ErrorResponse;
-use crate::common::types::Status;
+use crate::auth::helper::{ensure_login_allowed, login_attempt_keys, revoke_all_sessions};
+use crate::auth::model::LoginAttempt;
+use crate::category::product::stock::helper::spawn_low_stock_check;
+use crate::common::email::{escape_html, send_email, Mailer};
+use crate::common::error::AppError;
+use crate::common::i18n::{requested_locale_or, Message};
+use crate::common::jwt::{generate_verification_jwt, validate_jwt, Claims, EMAIL_CHANGE_PURPOSE, EMAIL_VERIFICATION_PURPOSE};
 use crate::config::CONFIG;
 use crate::database::MongoRepository;
-use crate::store::helper::get_store_from_headers;
-use crate::user::model::Rank;
-
-pub fn validate_security_key(headers: &HeaderMap) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
-    let security_key = headers.get("X-Vronix-Security").and_then(|h| h.to_str().ok());
-    if let Some(key) = security_key {
-        if key != CONFIG.vronix_security_key {
-            let error_response = ErrorResponse {
-                status: Status::Failure,
-                message: "유효하지 않은 보안 키입니다.".to_string(),
-            };
-            return Err((StatusCode::UNAUTHORIZED, Json(error_response)));
-        }
-    } else {
-        let error_response = ErrorResponse {
-            status: Status::Failure,
-            message: "보안 키가 제공되지 않았습니다.".to_string(),
-        };
-        return Err((StatusCode::BAD_REQUEST, Json(error_response)));
+use crate::reservation::helper::release_reservation;
+use crate::store::model::Store;
+use crate::user::model::{RegisterRequest, User};
+
+pub fn validate_security_key(headers: &HeaderMap) -> Result<(), AppError> {
+    match headers.get("X-Vronix-Security").and_then(|h| h.to_str().ok()) {
+        Some(key) if key == CONFIG.vronix_security_key => Ok(()),
+        Some(_) => Err(AppError::Unauthorized(Message::new("INVALID_SECURITY_KEY"))),
+        None => Err(AppError::Unauthorized(Message::new("SECURITY_KEY_REQUIRED"))),
     }
+}
 
-    Ok(())
+// 토큰 형식과 서명만 확인하므로 DB 를 조회하기 전에 인증 정보가 없거나 잘못된 요청을 걸러낼 수 있습니다.
+pub fn bearer_claims(headers: &HeaderMap) -> Result<Claims, AppError> {
+    let token = headers
+        .get("Authorization")
+        .and_then(|h| h.to_str().ok())
+        .and_then(|h| h.strip_prefix("Bearer "))
+        .map(str::trim)
+        .ok_or_else(|| AppError::Unauthorized(Message::new("AUTHORIZATION_REQUIRED")))?;
+
+    validate_jwt(token)
+        .map(|token| token.claims)
+        .map_err(|_| AppError::Unauthorized(Message::new("INVALID_TOKEN")))
 }
 
-pub fn validate_authorization(
-    headers: &HeaderMap,
+pub async fn validate_authorization(
+    claims: &Claims,
     expected_store_id: &str,
-) -> Result<(String, String), (StatusCode, Json<ErrorResponse>)> {
-    if let Some(auth_header) = headers.get("Authorization").and_then(|h| h.to_str().ok()) {
-        if auth_header.starts_with("Bearer ") {
-            let token = auth_header.trim_start_matches("Bearer ").trim();
-            return match validate_jwt(token) {
-                Ok(claims) => {
-                    if claims.claims.store_id == expected_store_id {
-                        Ok((claims.claims.store_id, claims.claims.id))
-                    } else {
-                        Err((
-                            StatusCode::UNAUTHORIZED,
-                            Json(ErrorResponse {
-                                status: Status::Failure,
-                                message: "잘못된 스토어 접근입니다.".to_string(),
-                            }),
-                        ))
-                    }
-                }
-                Err(_) => {
-                    Err((
-                        StatusCode::UNAUTHORIZED,
-                        Json(ErrorResponse {
-                            status: Status::Failure,
-                            message: "유효하지 않은 토큰입니다.".to_string(),
-                        }),
-                    ))
-                }
-            };
+    mongo_repo: &Arc<MongoRepository>,
+) -> Result<(), AppError> {
+    if claims.store_id != expected_store_id {
+        return Err(AppError::Unauthorized(Message::new("STORE_MISMATCH")));
+    }
+
+    let (Ok(store_id), Ok(user_id)) = (ObjectId::from_str(&claims.store_id), ObjectId::from_str(&claims.id)) else {
+        return Err(AppError::Unauthorized(Message::new("INVALID_TOKEN")));
+    };
+    let issued_at = DateTime::from_millis(claims.issued_at_millis());
+
+    if mongo_repo.is_access_token_revoked(&store_id, &user_id, &claims.jti, &claims.sid, issued_at).await? {
+        return Err(AppError::Unauthorized(Message::new("TOKEN_REVOKED")));
+    }
+
+    Ok(())
+}
+
+pub async fn authenticate(
+    claims: Claims,
+    store: &Store,
+    mongo_repo: &Arc<MongoRepository>,
+) -> Result<(User, Claims), AppError> {
+    validate_authorization(&claims, &store.object_id.unwrap().to_string(), mongo_repo).await?;
+
+    let user_id = ObjectId::from_str(&claims.id)
+        .map_err(|_| AppError::Validation(Message::new("INVALID_USER_ID")))?;
+
+    let user = mongo_repo
+        .find_user_by_id(&store.object_id.unwrap(), &user_id)
+        .await?
+        .ok_or_else(|| AppError::Unauthorized(Message::new("USER_NOT_FOUND")))?;
+    ensure_not_suspended(&user)?;
+
+    if let Ok(session_id) = ObjectId::from_str(&claims.sid) {
+        mongo_repo.mark_session_seen(&session_id).await?;
+    }
+
+    Ok((user, claims))
+}
+
+pub fn ensure_not_suspended(user: &User) -> Result<(), AppError> {
+    if user.suspended {
+        return Err(AppError::Forbidden(Message::new("ACCOUNT_SUSPENDED")));
+    }
+
+    Ok(())
+}
+
+pub fn validate_new_password(password: &str, confirm_password: &str) -> Result<(), AppError> {
+    if password != confirm_password {
+        return Err(AppError::Validation(Message::new("PASSWORD_MISMATCH")));
+    }
+
+    Ok(())
+}
+
+pub async fn validate_new_user(
+    mongo_repo: &Arc<MongoRepository>,
+    store_id: &ObjectId,
+    body: &RegisterRequest,
+) -> Result<(), AppError> {
+    if !is_valid_email(&body.email) {
+        return Err(AppError::Validation(Message::new("INVALID_EMAIL")));
+    }
+
+    validate_new_password(&body.password, &body.confirm_password)?;
+
+    if mongo_repo.find_user_by_email(store_id, &body.email).await?.is_some() {
+        return Err(AppError::Conflict(Message::new("EMAIL_TAKEN")));
+    }
+
+    if mongo_repo.find_user_by_user_id(store_id, &body.user_id).await?.is_some() {
+        return Err(AppError::Conflict(Message::new("USER_ID_TAKEN")));
+    }
+
+    Ok(())
+}
+
+pub fn ensure_email_verified(store: &Store, user: &User) -> Result<(), AppError> {
+    if store.require_verified_email && !user.verified {
+        return Err(AppError::Forbidden(Message::new("EMAIL_NOT_VERIFIED")));
+    }
+
+    Ok(())
+}
+
+pub fn is_valid_email(email: &str) -> bool {
+    match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+                && !email.chars().any(char::is_whitespace)
         }
+        None => false,
     }
+}
+
+pub fn spawn_verification_email(mailer: Arc<dyn Mailer>, store: Store, user: User) {
+    let locale = requested_locale_or(store.default_locale);
+    tokio::spawn(async move {
+        let token = generate_verification_jwt(
+            user.object_id.unwrap(),
+            store.object_id.unwrap(),
+            &user.email,
+            EMAIL_VERIFICATION_PURPOSE,
+            &CONFIG.jwt_secret,
+        );
+        let link = format!("{}?token={}", CONFIG.email_verification_url, token);
+        let subject = Message::new("MAIL_VERIFY_SUBJECT").arg(&store.name).render(locale);
+        let body = format!(
+            r#"<div style="font-family: sans-serif;">
+  <h2>{}</h2>
+  <p>{}</p>
+  <p><a href="{}">{}</a></p>
+  <p>{}</p>
+</div>"#,
+            Message::new("MAIL_VERIFY_TITLE").render(locale),
+            Message::new("MAIL_VERIFY_BODY").arg(escape_html(&user.user_id)).render(locale),
+            escape_html(&link),
+            Message::new("MAIL_VERIFY_LINK").render(locale),
+            Message::new("MAIL_LINK_VALID_HOURS").arg(CONFIG.email_verification_ttl_secs / 3600).render(locale),
+        );
 
-    Err((
-        StatusCode::BAD_REQUEST,
-        Json(ErrorResponse {
-            status: Status::Failure,
-            message: "Authorization 헤더가 제공되지 않았습니다.".to_string(),
-        }),
-    ))
+        if let Err(err) = send_email(&mailer, &store.sender(), vec![user.email.clone()], &subject, &body).await {
+            println!("인증 메일 발송 실패 ('{}'): {}", user.email, err);
+        }
+    });
+}
+
+pub fn spawn_email_change_email(mailer: Arc<dyn Mailer>, store: Store, user: User, new_email: String) {
+    let locale = requested_locale_or(store.default_locale);
+    tokio::spawn(async move {
+        let token = generate_verification_jwt(
+            user.object_id.unwrap(),
+            store.object_id.unwrap(),
+            &new_email,
+            EMAIL_CHANGE_PURPOSE,
+            &CONFIG.jwt_secret,
+        );
+        let link = format!("{}?token={}", CONFIG.email_verification_url, token);
+        let subject = Message::new("MAIL_EMAIL_CHANGE_SUBJECT").arg(&store.name).render(locale);
+        let body = format!(
+            r#"<div style="font-family: sans-serif;">
+  <h2>{}</h2>
+  <p>{}</p>
+  <p><a href="{}">{}</a></p>
+  <p>{} {}</p>
+</div>"#,
+            Message::new("MAIL_EMAIL_CHANGE_TITLE").render(locale),
+            Message::new("MAIL_EMAIL_CHANGE_BODY").arg(escape_html(&user.user_id)).render(locale),
+            escape_html(&link),
+            Message::new("MAIL_EMAIL_CHANGE_LINK").render(locale),
+            Message::new("MAIL_LINK_VALID_HOURS").arg(CONFIG.email_verification_ttl_secs / 3600).render(locale),
+            Message::new("MAIL_IGNORE_IF_NOT_REQUESTED").render(locale),
+        );
+
+        if let Err(err) = send_email(&mailer, &store.sender(), vec![new_email.clone()], &subject, &body).await {
+            println!("이메일 변경 인증 메일 발송 실패 ('{}'): {}", new_email, err);
+        }
+    });
 }
 
-pub async fn validate_user_rank(
-    headers: &HeaderMap,
-    required_rank: Rank,
+// 토큰을 가진 쪽이 현재 비밀번호를 무차별 대입하지 못하도록 로그인과 같은 실패 횟수 제한을 적용합니다.
+pub async fn verify_current_password(
     mongo_repo: &Arc<MongoRepository>,
-) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
-    let store = match get_store_from_headers(headers, mongo_repo).await {
-        Ok(store) => store,
-        Err(err) => return Err(err),
-    };
+    store_id: &ObjectId,
+    user: &User,
+    password: &str,
+    ip: IpAddr,
+) -> Result<(), AppError> {
+    let attempt = ensure_login_allowed(mongo_repo, store_id, login_attempt_keys(&user.user_id, ip)).await?;
 
-    let (_store_id, user_id_str) = match validate_authorization(headers, &store.object_id.unwrap().to_string()) {
-        Ok((store_id, user_id)) => (store_id, user_id),
-        Err(err) => return Err(err),
-    };
+    if !verify(password, &user.password)? {
+        attempt.fail().await;
+        return Err(AppError::Unauthorized(Message::new("INVALID_CURRENT_PASSWORD")));
+    }
 
-    match ObjectId::from_str(&user_id_str) {
-        Ok(user_id) => {
-            println!("User ID: {}", user_id);
-            match mongo_repo.find_user_by_id(&store.object_id.unwrap(), &user_id).await {
-                Some(user) => {
-                    if user.rank >= required_rank {
-                        Ok(())
-                    } else {
-                        Err((
-                            StatusCode::FORBIDDEN,
-                            Json(ErrorResponse {
-                                status: Status::Failure,
-                                message: "권한이 부족합니다.".to_string(),
-                            }),
-                        ))
-                    }
-                }
-                None => Err((
-                    StatusCode::UNAUTHORIZED,
-                    Json(ErrorResponse {
-                        status: Status::Failure,
-                        message: "유저를 찾을 수 없습니다.".to_string(),
-                    }),
-                )),
-            }
+    Ok(())
+}
+
+// 주문과 예약 기록은 스토어 정산에 필요하므로 지우지 않고 유저 연결만 끊습니다.
+pub async fn delete_account(
+    mongo_repo: &Arc<MongoRepository>,
+    mailer: &Arc<dyn Mailer>,
+    store: &Store,
+    user: &User,
+) -> Result<(), AppError> {
+    let store_id = store.object_id.unwrap();
+    let user_id = user.object_id.unwrap();
+
+    while let Some(reservation) = mongo_repo.cancel_next_user_reservation(&store_id, &user_id).await? {
+        match release_reservation(mongo_repo, &reservation).await {
+            Ok(_) => spawn_low_stock_check(
+                mongo_repo.clone(),
+                mailer.clone(),
+                store.clone(),
+                reservation.category_name,
+                reservation.product_name,
+            ),
+            Err(err) => println!(
+                "탈퇴한 유저의 예약 {:?} 재고 반환 실패, 다음 정리 때 다시 시도합니다: {}",
+                reservation.object_id, err
+            ),
         }
-        Err(_) => Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                status: Status::Failure,
-                message: "유효하지 않은 유저 ID 형식입니다.".to_string(),
-            }))),
     }
-}
\ No newline at end of file
+
+    mongo_repo.anonymize_user_orders(&store_id, &user_id).await?;
+    mongo_repo.anonymize_user_reservations(&store_id, &user_id).await?;
+    mongo_repo.delete_user_restock_subscriptions(&store_id, &user_id).await?;
+    mongo_repo.delete_user_password_reset_tokens(&store_id, &user_id).await?;
+    mongo_repo.delete_user_api_keys(&store_id, &user_id).await?;
+    mongo_repo.clear_login_failures(&store_id, &LoginAttempt::account_key(&user.user_id)).await?;
+    revoke_all_sessions(mongo_repo, store_id, user_id).await?;
+    mongo_repo.delete_user(&store_id, &user_id).await?;
+
+    Ok(())
+}
@@ -1,4 +1,6 @@
 use bson::oid::ObjectId;
+use bson::serde_helpers::chrono_datetime_as_bson_datetime_optional;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use crate::common::types::Status;
 
@@ -17,14 +19,35 @@ pub struct User {
     pub store_id: Option<ObjectId>,
     pub user_id: String,
     pub email: String,
+    #[serde(default)]
+    pub pending_email: Option<String>,
     pub password: String,
     pub rank: Rank,
+    #[serde(default)]
+    pub roles: Vec<String>,
+    #[serde(default)]
+    pub verified: bool,
+    #[serde(default)]
+    pub suspended: bool,
+    #[serde(default, with = "chrono_datetime_as_bson_datetime_optional")]
+    pub verification_sent_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub totp_enabled: bool,
+    #[serde(default)]
+    pub totp_secret: Option<String>,
+    #[serde(default)]
+    pub totp_pending_secret: Option<String>,
+    #[serde(default)]
+    pub totp_last_step: Option<i64>,
+    #[serde(default)]
+    pub recovery_codes: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct UserResponse {
     pub status: Status,
     pub token: String,
+    pub refresh_token: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -39,4 +62,90 @@ pub struct RegisterRequest {
 pub struct LoginRequest {
     pub user_id: String,
     pub password: String,
-}
\ No newline at end of file
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct VerifyEmailRequest {
+    pub token: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UserProfile {
+    pub id: Option<String>,
+    pub user_id: String,
+    pub email: String,
+    pub pending_email: Option<String>,
+    pub rank: Rank,
+    pub roles: Vec<String>,
+    pub verified: bool,
+    pub suspended: bool,
+    pub totp_enabled: bool,
+}
+
+impl From<User> for UserProfile {
+    fn from(user: User) -> Self {
+        UserProfile {
+            id: user.object_id.map(|id| id.to_hex()),
+            user_id: user.user_id,
+            email: user.email,
+            pending_email: user.pending_email,
+            rank: user.rank,
+            roles: user.roles,
+            verified: user.verified,
+            suspended: user.suspended,
+            totp_enabled: user.totp_enabled,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UserProfileResponse {
+    pub status: Status,
+    pub user: UserProfile,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateProfileRequest {
+    pub email: Option<String>,
+    pub current_password: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChangePasswordRequest {
+    pub current_password: String,
+    pub password: String,
+    pub confirm_password: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct DeleteAccountRequest {
+    pub password: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AdminUserListQuery {
+    pub query: Option<String>,
+    pub rank: Option<Rank>,
+    pub suspended: Option<bool>,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UserFilter {
+    pub query: Option<String>,
+    pub rank: Option<Rank>,
+    pub suspended: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UserListResponse {
+    pub status: Status,
+    pub users: Vec<UserProfile>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateRankRequest {
+    pub rank: Rank,
+}
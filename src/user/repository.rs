@@ -1,8 +1,12 @@
-use mongodb::bson::doc;
+use chrono::{Duration, Utc};
+use mongodb::bson::{doc, to_bson, DateTime};
 use mongodb::bson::oid::ObjectId;
 use mongodb::error::Result;
+use mongodb::Cursor;
+use mongodb::options::ReturnDocument;
+use crate::config::CONFIG;
 use crate::database::MongoRepository;
-use crate::user::model::User;
+use crate::user::model::{Rank, User, UserFilter};
 
 impl MongoRepository {
     pub async fn create_user(&self, new_user: User) -> Result<ObjectId> {
@@ -10,19 +14,262 @@ impl MongoRepository {
         Ok(user.inserted_id.as_object_id().unwrap())
     }
 
-    #[allow(dead_code)]
-    pub async fn find_user_by_id(&self, store_id: &ObjectId, id: &ObjectId) -> Option<User> {
+    pub async fn find_user_by_id(&self, store_id: &ObjectId, id: &ObjectId) -> Result<Option<User>> {
         let filter = doc! { "_id": id, "store_id": store_id };
-        self.user_collection.find_one(filter).await.unwrap_or(None)
+        self.user_collection.find_one(filter).await
     }
 
-    pub async fn find_user_by_email(&self, store_id: &ObjectId, email: &str) -> Option<User> {
+    pub async fn find_user_by_email(&self, store_id: &ObjectId, email: &str) -> Result<Option<User>> {
         let filter = doc! { "store_id": store_id, "email": email };
-        self.user_collection.find_one(filter).await.unwrap_or(None)
+        self.user_collection.find_one(filter).await
     }
 
-    pub async fn find_user_by_user_id(&self, store_id: &ObjectId, user_id: &str) -> Option<User> {
+    pub async fn find_user_by_user_id(&self, store_id: &ObjectId, user_id: &str) -> Result<Option<User>> {
         let filter = doc! { "store_id": store_id, "user_id": user_id };
-        self.user_collection.find_one(filter).await.unwrap_or(None)
+        self.user_collection.find_one(filter).await
     }
-}
\ No newline at end of file
+
+    pub async fn find_users_by_rank(&self, store_id: &ObjectId, rank: Rank) -> Result<Vec<User>> {
+        let filter = doc! { "store_id": store_id, "rank": to_bson(&rank)? };
+        let mut cursor = self.user_collection.find(filter).await?;
+
+        let mut users = vec![];
+        while cursor.advance().await? {
+            users.push(cursor.deserialize_current()?);
+        }
+        Ok(users)
+    }
+
+    pub async fn update_user_password(&self, store_id: &ObjectId, id: &ObjectId, password: &str) -> Result<bool> {
+        let filter = doc! { "_id": id, "store_id": store_id };
+        let update = doc! { "$set": { "password": password } };
+        let result = self.user_collection.update_one(filter, update).await?;
+        Ok(result.matched_count > 0)
+    }
+
+    pub async fn mark_user_verified(&self, store_id: &ObjectId, id: &ObjectId, email: &str) -> Result<bool> {
+        let filter = doc! { "_id": id, "store_id": store_id, "email": email };
+        let update = doc! { "$set": { "verified": true } };
+        let result = self.user_collection.update_one(filter, update).await?;
+        Ok(result.matched_count > 0)
+    }
+
+    pub async fn claim_verification_resend(&self, store_id: &ObjectId, id: &ObjectId) -> Result<bool> {
+        let now = Utc::now();
+        let cooldown_start = now - Duration::seconds(CONFIG.email_verification_cooldown_secs);
+        let filter = doc! {
+            "_id": id,
+            "store_id": store_id,
+            "verified": { "$ne": true },
+            "$or": [
+                { "verification_sent_at": null },
+                { "verification_sent_at": { "$lte": DateTime::from_chrono(cooldown_start) } },
+            ],
+        };
+        let update = doc! { "$set": { "verification_sent_at": DateTime::from_chrono(now) } };
+        let result = self.user_collection.update_one(filter, update).await?;
+        Ok(result.modified_count > 0)
+    }
+
+    pub async fn set_totp_pending_secret(&self, store_id: &ObjectId, id: &ObjectId, secret: &str) -> Result<bool> {
+        let filter = doc! { "_id": id, "store_id": store_id, "totp_enabled": { "$ne": true } };
+        let update = doc! { "$set": { "totp_pending_secret": secret } };
+        let result = self.user_collection.update_one(filter, update).await?;
+        Ok(result.matched_count > 0)
+    }
+
+    pub async fn enable_totp(
+        &self,
+        store_id: &ObjectId,
+        id: &ObjectId,
+        secret: &str,
+        step: i64,
+        recovery_codes: &[String],
+    ) -> Result<bool> {
+        let filter = doc! {
+            "_id": id,
+            "store_id": store_id,
+            "totp_enabled": { "$ne": true },
+            "totp_pending_secret": secret,
+        };
+        let update = doc! {
+            "$set": {
+                "totp_enabled": true,
+                "totp_secret": secret,
+                "totp_last_step": step,
+                "recovery_codes": recovery_codes,
+            },
+            "$unset": { "totp_pending_secret": "" },
+        };
+        let result = self.user_collection.update_one(filter, update).await?;
+        Ok(result.modified_count > 0)
+    }
+
+    pub async fn find_users_with_totp_secret(&self) -> Result<Cursor<User>> {
+        let filter = doc! {
+            "$or": [
+                { "totp_secret": { "$type": "string" } },
+                { "totp_pending_secret": { "$type": "string" } },
+            ]
+        };
+        self.user_collection.find(filter).await
+    }
+
+    pub async fn replace_totp_secret(&self, id: &ObjectId, field: &str, current: &str, replacement: &str) -> Result<bool> {
+        let filter = doc! { "_id": id, field: current };
+        let update = doc! { "$set": { field: replacement } };
+        let result = self.user_collection.update_one(filter, update).await?;
+        Ok(result.modified_count > 0)
+    }
+
+    pub async fn disable_totp(&self, store_id: &ObjectId, id: &ObjectId) -> Result<bool> {
+        let filter = doc! { "_id": id, "store_id": store_id, "totp_enabled": true };
+        let update = doc! {
+            "$set": { "totp_enabled": false, "recovery_codes": [] },
+            "$unset": { "totp_secret": "", "totp_pending_secret": "", "totp_last_step": "" },
+        };
+        let result = self.user_collection.update_one(filter, update).await?;
+        Ok(result.modified_count > 0)
+    }
+
+    pub async fn claim_totp_step(&self, store_id: &ObjectId, id: &ObjectId, step: i64) -> Result<bool> {
+        let filter = doc! {
+            "_id": id,
+            "store_id": store_id,
+            "totp_enabled": true,
+            "$or": [
+                { "totp_last_step": null },
+                { "totp_last_step": { "$lt": step } },
+            ],
+        };
+        let update = doc! { "$set": { "totp_last_step": step } };
+        let result = self.user_collection.update_one(filter, update).await?;
+        Ok(result.modified_count > 0)
+    }
+
+    pub async fn use_recovery_code(&self, store_id: &ObjectId, id: &ObjectId, code_hash: &str) -> Result<bool> {
+        let filter = doc! { "_id": id, "store_id": store_id, "totp_enabled": true, "recovery_codes": code_hash };
+        let update = doc! { "$pull": { "recovery_codes": code_hash } };
+        let result = self.user_collection.update_one(filter, update).await?;
+        Ok(result.modified_count > 0)
+    }
+
+    pub async fn request_email_change(&self, store_id: &ObjectId, id: &ObjectId, email: &str) -> Result<bool> {
+        let now = Utc::now();
+        let cooldown_start = now - Duration::seconds(CONFIG.email_verification_cooldown_secs);
+        let filter = doc! {
+            "_id": id,
+            "store_id": store_id,
+            "$or": [
+                { "verification_sent_at": null },
+                { "verification_sent_at": { "$lte": DateTime::from_chrono(cooldown_start) } },
+            ],
+        };
+        let update = doc! {
+            "$set": {
+                "pending_email": email,
+                "verification_sent_at": DateTime::from_chrono(now),
+            }
+        };
+        let result = self.user_collection.update_one(filter, update).await?;
+        Ok(result.matched_count > 0)
+    }
+
+    pub async fn confirm_email_change(&self, store_id: &ObjectId, id: &ObjectId, email: &str) -> Result<bool> {
+        let filter = doc! { "_id": id, "store_id": store_id, "pending_email": email };
+        let update = doc! {
+            "$set": { "email": email, "verified": true },
+            "$unset": { "pending_email": "" },
+        };
+        let result = self.user_collection.update_one(filter, update).await?;
+        Ok(result.modified_count > 0)
+    }
+
+    pub async fn delete_user(&self, store_id: &ObjectId, id: &ObjectId) -> Result<bool> {
+        let filter = doc! { "_id": id, "store_id": store_id };
+        let result = self.user_collection.delete_one(filter).await?;
+        Ok(result.deleted_count > 0)
+    }
+
+    pub async fn find_users(
+        &self,
+        store_id: &ObjectId,
+        filter: &UserFilter,
+        cursor: Option<ObjectId>,
+        limit: i64,
+    ) -> Result<Vec<User>> {
+        let mut query = doc! { "store_id": store_id };
+
+        if let Some(keyword) = filter.query.as_deref().map(str::trim).filter(|keyword| !keyword.is_empty()) {
+            let pattern = doc! { "$regex": escape_regex(keyword), "$options": "i" };
+            query.insert("$or", vec![doc! { "user_id": pattern.clone() }, doc! { "email": pattern }]);
+        }
+        if let Some(rank) = &filter.rank {
+            query.insert("rank", to_bson(rank)?);
+        }
+        if let Some(suspended) = filter.suspended {
+            query.insert("suspended", if suspended { doc! { "$eq": true } } else { doc! { "$ne": true } });
+        }
+        if let Some(cursor) = cursor {
+            query.insert("_id", doc! { "$lt": cursor });
+        }
+
+        let mut cursor = self
+            .user_collection
+            .find(query)
+            .sort(doc! { "_id": -1 })
+            .limit(limit)
+            .await?;
+
+        let mut users = vec![];
+        while cursor.advance().await? {
+            users.push(cursor.deserialize_current()?);
+        }
+        Ok(users)
+    }
+
+    pub async fn update_user_rank(&self, store_id: &ObjectId, id: &ObjectId, rank: &Rank) -> Result<Option<User>> {
+        let filter = doc! { "_id": id, "store_id": store_id };
+        let update = doc! { "$set": { "rank": to_bson(rank)? } };
+        self.user_collection
+            .find_one_and_update(filter, update)
+            .return_document(ReturnDocument::After)
+            .await
+    }
+
+    pub async fn set_user_roles(&self, store_id: &ObjectId, id: &ObjectId, roles: &[String]) -> Result<Option<User>> {
+        let filter = doc! { "_id": id, "store_id": store_id };
+        let update = doc! { "$set": { "roles": roles } };
+        self.user_collection
+            .find_one_and_update(filter, update)
+            .return_document(ReturnDocument::After)
+            .await
+    }
+
+    pub async fn remove_role_from_users(&self, store_id: &ObjectId, role_name: &str) -> Result<()> {
+        let filter = doc! { "store_id": store_id, "roles": role_name };
+        let update = doc! { "$pull": { "roles": role_name } };
+        self.user_collection.update_many(filter, update).await?;
+        Ok(())
+    }
+
+    pub async fn set_user_suspended(&self, store_id: &ObjectId, id: &ObjectId, suspended: bool) -> Result<Option<User>> {
+        let filter = doc! { "_id": id, "store_id": store_id };
+        let update = doc! { "$set": { "suspended": suspended } };
+        self.user_collection
+            .find_one_and_update(filter, update)
+            .return_document(ReturnDocument::After)
+            .await
+    }
+}
+
+fn escape_regex(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if "\\^$.|?*+()[]{}".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}